@@ -32,6 +32,6 @@ fn main() {
         let new_tempo = new_tempo_string.trim().parse::<f32>().unwrap_or(tempo);
         println!("Playing MIDI at {new_tempo} BPM...");
         player.push_midi(&midi, synth, Some(new_tempo));
-        player.play();
+        player.play().unwrap();
     }
 }
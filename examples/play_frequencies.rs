@@ -9,5 +9,5 @@ fn main() {
     player.push(&400.0, &Duration::from_secs(1));
     player.push(&vec![400.0, 440.0], &Duration::from_secs(1));
     player.push(&vec![400.0, 440.0, 800.0], &Duration::from_secs(1));
-    player.play();
+    player.play().unwrap();
 }
@@ -27,5 +27,5 @@ fn main() {
     );
     player.push(&Interval::TRITONE, &Beat::WHOLE);
     player.push(&Interval::PERFECT_FIFTH, &Beat::WHOLE);
-    player.play();
+    player.play().unwrap();
 }
@@ -18,5 +18,5 @@ fn main() {
         let duration = beats[index % beats.len()];
         player.push(&note, &duration);
     }
-    player.play();
+    player.play().unwrap();
 }
@@ -13,7 +13,7 @@ fn main() {
         let chord = Chord::from_numeral(numeral, tonic, Some(octaves[index])).unwrap();
         player.push_arpeggiate(&chord, &Beat::SIXTEENTH, ArpeggioDirection::Up, 8);
     }
-    player.play();
+    player.play().unwrap();
     player
         .export_wav("example_play_progression.wav", BitsPerSample::TWENTYFOUR)
         .unwrap();
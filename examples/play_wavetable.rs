@@ -29,5 +29,5 @@ fn main() {
         }
         player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
     }
-    player.play();
+    player.play().unwrap();
 }
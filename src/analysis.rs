@@ -0,0 +1,162 @@
+use crate::chord::Chord;
+use crate::common::CadenceType;
+use crate::interval::Interval;
+use crate::note::Note;
+use crate::pitchclass::PitchClass;
+use crate::scale::Scale;
+
+/// Returns the indices at which two voices arrive at a parallel perfect fifth or a parallel octave,
+/// which occurs when both voices move from one perfect fifth to another or from one octave to
+/// another. Compound intervals are treated the same as their simple counterparts, and only the
+/// notes shared by both voices are compared.
+///
+/// # Parameters
+///
+/// - `voice_a`: A slice of [`Note`] representing the notes of the first voice.
+/// - `voice_b`: A slice of [`Note`] representing the notes of the second voice.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::analysis::find_parallel_fifths;
+/// use music_tools::note::Note;
+/// use std::str::FromStr;
+///
+/// let upper = ["G4", "A4"].map(|name| Note::from_str(name).unwrap());
+/// let lower = ["C4", "D4"].map(|name| Note::from_str(name).unwrap());
+/// assert_eq!(find_parallel_fifths(&upper, &lower), vec![1]);
+/// ```
+pub fn find_parallel_fifths(voice_a: &[Note], voice_b: &[Note]) -> Vec<usize> {
+    let mut indices: Vec<usize> = Vec::new();
+    let length = voice_a.len().min(voice_b.len());
+    for index in 1..length {
+        let prev_value =
+            Interval::between_notes(voice_a[index - 1], voice_b[index - 1]).get_value();
+        let curr_value = Interval::between_notes(voice_a[index], voice_b[index]).get_value();
+        let voices_move =
+            voice_a[index] != voice_a[index - 1] && voice_b[index] != voice_b[index - 1];
+        if voices_move && matches!(curr_value % 12, 0 | 7) && prev_value % 12 == curr_value % 12 {
+            indices.push(index);
+        }
+    }
+    indices
+}
+
+/// Returns the cadences found in a chord progression in a major key, paired with the index of the
+/// chord on which each cadence arrives. Each chord is identified as the tonic (I), subdominant
+/// (IV), dominant (V) or submediant (vi) chord of the key by the root and third of the chord, and
+/// each pair of consecutive chords is checked for an authentic, plagal, deceptive or half cadence.
+/// Chords without a tonic are ignored, and a half cadence is only detected if the chord before the
+/// dominant chord is not also a dominant chord.
+///
+/// # Parameters
+///
+/// - `chords`: A slice of [`Chord`] representing the chord progression.
+/// - `key_tonic`: A reference to the [`PitchClass`] of the tonic of the key.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::analysis::detect_cadences;
+/// use music_tools::chord::Chord;
+/// use music_tools::common::CadenceType;
+/// use music_tools::pitchclass::PitchClass;
+///
+/// let chords = ["I", "IV", "V7", "I"].map(|numeral| {
+///     Chord::from_numeral(numeral, PitchClass::C, None).unwrap()
+/// });
+/// assert_eq!(
+///     detect_cadences(&chords, &PitchClass::C),
+///     vec![(2, CadenceType::Half), (3, CadenceType::Authentic)]
+/// );
+/// ```
+pub fn detect_cadences(chords: &[Chord], key_tonic: &PitchClass) -> Vec<(usize, CadenceType)> {
+    let numerals: Vec<Option<&str>> = chords
+        .iter()
+        .map(|chord| {
+            let tonic = chord.get_tonic()?;
+            let degree = (tonic.get_value() as i16 - key_tonic.get_value() as i16).rem_euclid(12);
+            let values: Vec<u64> = chord
+                .get_intervals()
+                .iter()
+                .map(|interval| interval.get_value() % 12)
+                .collect();
+            let is_major = values.contains(&4) && values.contains(&7);
+            let is_minor = values.contains(&3) && values.contains(&7);
+            match degree {
+                0 if is_major => Some("I"),
+                5 if is_major => Some("IV"),
+                7 if is_major => Some("V"),
+                9 if is_minor => Some("vi"),
+                _ => None,
+            }
+        })
+        .collect();
+    let mut cadences: Vec<(usize, CadenceType)> = Vec::new();
+    for index in 1..numerals.len() {
+        if chords[index - 1].get_tonic().is_none() || chords[index].get_tonic().is_none() {
+            continue;
+        }
+        let cadence = match (numerals[index - 1], numerals[index]) {
+            (Some("V"), Some("I")) => CadenceType::Authentic,
+            (Some("IV"), Some("I")) => CadenceType::Plagal,
+            (Some("V"), Some("vi")) => CadenceType::Deceptive,
+            (Some("V"), Some("V")) => continue,
+            (_, Some("V")) => CadenceType::Half,
+            _ => continue,
+        };
+        cadences.push((index, cadence));
+    }
+    cadences
+}
+
+/// Returns the scale among a list of candidates which best matches the pitch classes of a melody
+/// with a known tonic, or [`None`] if there are no candidates. The best match is the candidate
+/// with the fewest differences between its pitch classes and the pitch classes of the melody,
+/// counting both the notes of the melody outside of the scale and the notes of the scale which are
+/// not used by the melody. If several candidates match equally well, the first one is returned.
+///
+/// # Parameters
+///
+/// - `notes`: A slice of [`Note`] representing the melody.
+/// - `tonic`: A reference to the [`PitchClass`] of the tonic of the melody.
+/// - `candidates`: A slice of [`Scale`] with the scales to choose from.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::analysis::detect_mode;
+/// use music_tools::common::{PentatonicType, ScaleType};
+/// use music_tools::note::Note;
+/// use music_tools::pitchclass::PitchClass;
+/// use music_tools::scale::Scale;
+/// use std::str::FromStr;
+///
+/// let melody: Vec<Note> = ["G4", "A4", "B4", "C5", "D5", "E5", "F5", "G5"]
+///     .into_iter()
+///     .map(|x| Note::from_str(x).unwrap())
+///     .collect();
+/// let candidates = [ScaleType::Major, ScaleType::Mixolydian]
+///     .map(|scale_type| Scale::try_new(scale_type, PentatonicType::None).unwrap());
+/// assert_eq!(
+///     detect_mode(&melody, &PitchClass::G, &candidates),
+///     Some(candidates[1].clone())
+/// );
+/// ```
+pub fn detect_mode(notes: &[Note], tonic: &PitchClass, candidates: &[Scale]) -> Option<Scale> {
+    let melody: Vec<PitchClass> = notes.iter().map(|note| note.get_pitch_class()).collect();
+    let mut best: Option<(&Scale, usize)> = None;
+    for scale in candidates {
+        let scale_pitch_classes = scale.to_pitch_classes(*tonic);
+        let outside = (0..12)
+            .filter_map(PitchClass::from_chromatic_index)
+            .filter(|pitch_class| {
+                melody.contains(pitch_class) != scale_pitch_classes.contains(pitch_class)
+            })
+            .count();
+        if !matches!(best, Some((_, best_outside)) if best_outside <= outside) {
+            best = Some((scale, outside));
+        }
+    }
+    best.map(|(scale, _)| scale.clone())
+}
@@ -11,3 +11,6 @@ pub mod processor;
 /// The wavetable submodule contains a wavetable oscillator synthesizer that can be used by the
 /// audio processor.
 pub mod wavetable;
+
+/// The mixer submodule contains functions for combining rendered audio buffers.
+pub mod mixer;
@@ -1,10 +1,13 @@
 use crate::chord::Chord;
+pub use crate::common::ArpeggioDirection;
+use crate::common::XorShiftRng;
 use crate::interval::Interval;
 use crate::note::Note;
 use crate::pitchclass::PitchClass;
 use crate::scale::Scale;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 /// A trait representing any type that can be used as a synthesizer for the audio processor.
 pub trait Synth {
@@ -22,12 +25,58 @@ pub trait Synth {
     ///
     /// - `frequency`: The frequency in hertz of the voice to be played.
     fn add_voice(&mut self, frequency: f32);
+    /// Adds a voice which will play a frequency on the synthesizer with a given amplitude. By
+    /// default the amplitude is ignored and the voice is added at full amplitude, but synthesizers
+    /// can override this function in order to play voices with different dynamics.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: The frequency in hertz of the voice to be played.
+    /// - `amplitude`: The amplitude of the voice between 0.0 and 1.0.
+    fn add_voice_with_amplitude(&mut self, frequency: f32, amplitude: f32) {
+        let _ = amplitude;
+        self.add_voice(frequency);
+    }
     /// Stops or removes a voice which is being played on the synthesizer.
     ///
     /// # Parameters
     ///
     /// - `frequency`: The frequency in hertz of the voice that will stop being played.
     fn remove_voice(&mut self, frequency: f32);
+    /// Changes the frequency of a voice which is being played on the synthesizer. By default the
+    /// voice is removed and a new voice is added, but synthesizers can override this function in
+    /// order to change the frequency without restarting the voice.
+    ///
+    /// # Parameters
+    ///
+    /// - `old_frequency`: The frequency in hertz of the voice that is being played.
+    /// - `new_frequency`: The frequency in hertz that the voice will play instead.
+    fn change_voice(&mut self, old_frequency: f32, new_frequency: f32) {
+        self.remove_voice(old_frequency);
+        self.add_voice(new_frequency);
+    }
+    /// Releases a voice which is being played on the synthesizer, allowing it to fade out instead
+    /// of stopping abruptly. By default the voice is removed immediately, but synthesizers can
+    /// override this function in order to implement a release stage.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: The frequency in hertz of the voice that will be released.
+    fn release_voice(&mut self, frequency: f32) {
+        self.remove_voice(frequency);
+    }
+    /// Returns true if the synthesizer has finished playing all of its voices, including the voices
+    /// that are still fading out after being released. By default this function returns true.
+    fn is_silent(&self) -> bool {
+        true
+    }
+    /// Returns the latency of the synthesizer, which is the time between a voice being added and
+    /// the synthesizer starting to produce its sound. The audio player starts the voices of
+    /// synthesizers with a lower latency later so that all of them sound at the same time. By
+    /// default the latency is zero.
+    fn get_latency(&self) -> Duration {
+        Duration::ZERO
+    }
     /// Returns the current sample that is being produced by the synthesizer as an [`f32`].
     fn get_sample(&mut self) -> f32;
     /// Advances the synthesizer to play the next sample.
@@ -45,6 +94,17 @@ pub trait Playable {
     /// Returns a list of frequencies in hertz for each of the individual audio elements contained
     /// by the structure.
     fn get_frequencies(&self) -> Vec<f32>;
+    /// Returns a list of frequencies in hertz for each of the individual audio elements contained
+    /// by the structure using a reference frequency for the note A4. Structures made of notes are
+    /// retuned to the reference, while raw frequencies are returned unchanged, which is the
+    /// default.
+    ///
+    /// # Parameters
+    ///
+    /// - `a4_hz`: The reference frequency in hertz of the note A4.
+    fn get_frequencies_at_reference(&self, _a4_hz: f32) -> Vec<f32> {
+        self.get_frequencies()
+    }
 }
 
 impl Playable for f32 {
@@ -63,10 +123,18 @@ impl Playable for Note {
     fn get_frequencies(&self) -> Vec<f32> {
         vec![self.get_frequency()]
     }
+
+    fn get_frequencies_at_reference(&self, a4_hz: f32) -> Vec<f32> {
+        vec![self.get_frequency_at_reference(a4_hz)]
+    }
 }
 
 impl Playable for Chord {
     fn get_frequencies(&self) -> Vec<f32> {
+        self.get_frequencies_at_reference(Note::default().get_base_frequency())
+    }
+
+    fn get_frequencies_at_reference(&self, a4_hz: f32) -> Vec<f32> {
         let default_note = Note::default();
         let mut chord = self.clone();
         //If the chord is missing data, middle C is chosen as the tonic
@@ -77,47 +145,190 @@ impl Playable for Chord {
             chord.set_octave(Some(default_note.get_octave()));
         }
         let notes = Vec::<Note>::try_from(chord).unwrap();
-        notes.iter().map(|note| note.get_frequency()).collect()
+        notes
+            .iter()
+            .map(|note| note.get_frequency_at_reference(a4_hz))
+            .collect()
     }
 }
 
 impl Playable for Interval {
     fn get_frequencies(&self) -> Vec<f32> {
+        self.get_frequencies_at_reference(Note::default().get_base_frequency())
+    }
+
+    fn get_frequencies_at_reference(&self, a4_hz: f32) -> Vec<f32> {
         let tonic = Note::default();
         let interval_note = tonic.at_offset(self.get_value() as isize);
-        vec![tonic.get_frequency(), interval_note.get_frequency()]
+        vec![
+            tonic.get_frequency_at_reference(a4_hz),
+            interval_note.get_frequency_at_reference(a4_hz),
+        ]
     }
 }
 
 impl Playable for PitchClass {
     fn get_frequencies(&self) -> Vec<f32> {
+        self.get_frequencies_at_reference(Note::default().get_base_frequency())
+    }
+
+    fn get_frequencies_at_reference(&self, a4_hz: f32) -> Vec<f32> {
         let default_note = Note::default();
         let note = Note::new(*self, default_note.get_octave());
-        vec![note.get_frequency()]
+        vec![note.get_frequency_at_reference(a4_hz)]
     }
 }
 
 impl Playable for Scale {
     fn get_frequencies(&self) -> Vec<f32> {
+        self.get_frequencies_at_reference(Note::default().get_base_frequency())
+    }
+
+    fn get_frequencies_at_reference(&self, a4_hz: f32) -> Vec<f32> {
         let default_note = Note::default();
         let notes = self.to_notes(default_note.get_pitch_class(), default_note.get_octave());
-        notes.iter().map(|note| note.get_frequency()).collect()
+        notes
+            .iter()
+            .map(|note| note.get_frequency_at_reference(a4_hz))
+            .collect()
     }
 }
 
-/// An enum that can be used to control the direction of arpeggiation.
+/// An enum that can be used to control how the audio processor mixes the samples of the
+/// synthesizers that are registered in it.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub enum ArpeggioDirection {
-    /// The arpeggio ascends from lowest to highest frequency before looping back around to the
-    /// lowest frequency.
+pub enum MixingMode {
+    /// The samples are added together and divided by the square root of the amount of
+    /// synthesizers, which keeps the perceived loudness similar as synthesizers are added.
     #[default]
-    Up,
-    /// The arpeggio descends from highest to lowest frequency before looping back around to the
-    /// highest frequency.
-    Down,
-    /// The arpeggio ascends from lowest to highest frequency, and once it reaches its peak
-    /// descends from highest to lowest frequency, essentially forming a cycle.
-    UpDown,
+    RmsNormalized,
+    /// The samples are added together without any normalization.
+    Sum,
+    /// The samples are averaged, dividing their sum by the amount of synthesizers.
+    Average,
+}
+
+/// An enum representing the tuning system that an audio player uses to calculate the frequencies
+/// of notes from their reference frequency.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Tuning {
+    /// Twelve-tone equal temperament, where every semitone has the same frequency ratio.
+    #[default]
+    Equal,
+}
+
+/// An enum that can be used to control how a wavetable oscillator reads the values between the
+/// points stored in its wavetable.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// The value of the nearest stored point is used, which gives the wave a stepped and lo-fi
+    /// character.
+    None,
+    /// The value is linearly interpolated between the two nearest stored points.
+    #[default]
+    Linear,
+    /// The value is interpolated with a Catmull-Rom spline over the four nearest stored points,
+    /// which gives a smoother wave than linear interpolation.
+    Cubic,
+}
+
+/// An enum that can be used to control how the frequency of a sweep changes over time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SweepShape {
+    /// The frequency changes by the same amount of hertz during each unit of time.
+    Linear,
+    /// The frequency changes by the same ratio during each unit of time, so that each octave takes
+    /// the same amount of time to sweep through.
+    #[default]
+    Logarithmic,
+}
+
+impl SweepShape {
+    /// Returns the frequency in hertz of a sweep at a given point of its progress.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_frequency`: The frequency in hertz at the start of the sweep. This must be greater
+    ///   than zero for logarithmic sweeps.
+    /// - `end_frequency`: The frequency in hertz at the end of the sweep. This must be greater than
+    ///   zero for logarithmic sweeps.
+    /// - `progress`: A value between 0.0 and 1.0 representing how far along the sweep is. Values
+    ///   outside of this range are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::SweepShape;
+    ///
+    /// assert_eq!(SweepShape::Linear.get_frequency(100.0, 300.0, 0.5), 200.0);
+    /// assert_eq!(SweepShape::Logarithmic.get_frequency(100.0, 400.0, 0.5), 200.0);
+    /// ```
+    pub fn get_frequency(&self, start_frequency: f32, end_frequency: f32, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            SweepShape::Linear => start_frequency + (end_frequency - start_frequency) * progress,
+            SweepShape::Logarithmic => {
+                start_frequency * (end_frequency / start_frequency).powf(progress)
+            }
+        }
+    }
+}
+
+/// An enum representing the different kinds of percussion hits that can be synthesized.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DrumKind {
+    /// A kick drum, which is synthesized as a sine wave that quickly drops in pitch and volume.
+    #[default]
+    Kick,
+    /// A snare drum, which is synthesized as a burst of band limited noise mixed with a short
+    /// tone.
+    Snare,
+    /// A hi-hat, which is synthesized as a very short burst of high frequency noise.
+    HiHat,
+}
+
+impl DrumKind {
+    /// Renders a single hit of the drum into a [`Vec<f32>`] of samples, independently of any
+    /// synthesizer.
+    ///
+    /// # Parameters
+    ///
+    /// - `duration`: A [`Duration`] representing the length of the rendered audio. The hit decays
+    ///   quickly, so the remainder of a long duration will be nearly silent.
+    /// - `sample_rate`: A [`u32`] representing the sample rate in hertz of the rendered audio.
+    pub fn render(&self, duration: Duration, sample_rate: u32) -> Vec<f32> {
+        let samples = (duration.as_secs_f64() * sample_rate as f64) as usize;
+        let mut rng = XorShiftRng::new(samples as u64);
+        let mut table: Vec<f32> = Vec::with_capacity(samples);
+        let mut phase = 0.0;
+        let mut lowpass = 0.0;
+        let mut prev_noise = 0.0;
+        let mut prev_highpass = 0.0;
+        for index in 0..samples {
+            let time = index as f32 / sample_rate as f32;
+            let noise = 2.0 * rng.next_f32() - 1.0;
+            //One pole high pass filter to remove the low end of the noise
+            let highpass = 0.9 * (prev_highpass + noise - prev_noise);
+            prev_noise = noise;
+            prev_highpass = highpass;
+            let sample = match self {
+                DrumKind::Kick => {
+                    let frequency = 50.0 + 100.0 * f32::exp(-time * 30.0);
+                    phase = (phase + frequency / sample_rate as f32) % 1.0;
+                    sine_wave(phase) * f32::exp(-time * 12.0)
+                }
+                DrumKind::Snare => {
+                    //One pole low pass filter which together with the high pass forms a band pass
+                    lowpass += 0.5 * (highpass - lowpass);
+                    phase = (phase + 180.0 / sample_rate as f32) % 1.0;
+                    (0.7 * lowpass + 0.3 * sine_wave(phase)) * f32::exp(-time * 25.0)
+                }
+                DrumKind::HiHat => 0.5 * highpass * f32::exp(-time * 80.0),
+            };
+            table.push(sample.clamp(-1.0, 1.0));
+        }
+        table
+    }
 }
 
 /// An error which is returned when audio could not be played.
@@ -159,6 +370,14 @@ fn sawtooth_wave(time: f32) -> f32 {
     2.0 * time - 1.0
 }
 
+fn linear_curve(velocity: f32) -> f32 {
+    velocity
+}
+
+fn exponential_curve(velocity: f32) -> f32 {
+    (16.0_f32.powf(velocity) - 1.0) / 15.0
+}
+
 #[non_exhaustive]
 /// A structure containing common waveforms.
 pub struct Waveforms;
@@ -173,3 +392,16 @@ impl Waveforms {
     /// The sawtooth wave function with a period of 1 unit of time.
     pub const SAWTOOTH_WAVE: fn(f32) -> f32 = sawtooth_wave;
 }
+
+#[non_exhaustive]
+/// A structure containing common dynamics curves, which map a normalized velocity between 0.0 and
+/// 1.0 to an amplitude between 0.0 and 1.0.
+pub struct DynamicsCurves;
+
+impl DynamicsCurves {
+    /// The linear curve, where the amplitude is equal to the velocity.
+    pub const LINEAR: fn(f32) -> f32 = linear_curve;
+    /// The exponential curve, where the amplitude grows slowly for soft velocities and quickly for
+    /// loud velocities.
+    pub const EXPONENTIAL: fn(f32) -> f32 = exponential_curve;
+}
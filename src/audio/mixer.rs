@@ -0,0 +1,68 @@
+/// Mixes several audio buffers into a single buffer. The buffers are summed sample by sample, where
+/// shorter buffers are padded with silence, and the result is clamped between -1.0 and 1.0.
+///
+/// # Parameters
+///
+/// - `buffers`: A slice of [`Vec<f32>`] containing each of the buffers to be mixed together.
+/// - `gains`: A slice of [`f32`] with the gain to apply to each buffer with the same index. If a
+///   buffer does not have a corresponding gain then a gain of 1.0 is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::audio::mixer::mix_buffers;
+///
+/// let mixed = mix_buffers(&[vec![0.25, 0.25], vec![0.5]], &[1.0, 0.5]);
+/// assert_eq!(mixed, vec![0.5, 0.25]);
+/// ```
+pub fn mix_buffers(buffers: &[Vec<f32>], gains: &[f32]) -> Vec<f32> {
+    let length = buffers.iter().map(|buffer| buffer.len()).max().unwrap_or(0);
+    let mut mixed = vec![0.0; length];
+    for (index, buffer) in buffers.iter().enumerate() {
+        let gain = gains.get(index).copied().unwrap_or(1.0);
+        for (mixed_sample, sample) in mixed.iter_mut().zip(buffer) {
+            *mixed_sample += sample * gain;
+        }
+    }
+    for sample in mixed.iter_mut() {
+        *sample = sample.clamp(-1.0, 1.0);
+    }
+    mixed
+}
+
+/// Joins two audio buffers by overlapping the end of the first buffer with the start of the second
+/// buffer using an equal-power crossfade, so that the perceived loudness stays constant through the
+/// overlapping region. The rest of both buffers is concatenated around the overlap, and the result
+/// is clamped between -1.0 and 1.0. If the overlap is longer than either buffer it is shortened to
+/// the length of the shortest buffer.
+///
+/// # Parameters
+///
+/// - `a`: A slice of [`f32`] containing the buffer that fades out.
+/// - `b`: A slice of [`f32`] containing the buffer that fades in.
+/// - `overlap_samples`: The amount of samples during which both buffers are heard.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::audio::mixer::crossfade;
+///
+/// let joined = crossfade(&[0.5; 100], &[0.25; 50], 20);
+/// assert_eq!(joined.len(), 130);
+/// assert_eq!(joined[0], 0.5);
+/// assert_eq!(joined[129], 0.25);
+/// ```
+pub fn crossfade(a: &[f32], b: &[f32], overlap_samples: usize) -> Vec<f32> {
+    let overlap = overlap_samples.min(a.len()).min(b.len());
+    let fade_start = a.len() - overlap;
+    let mut joined: Vec<f32> = Vec::with_capacity(a.len() + b.len() - overlap);
+    joined.extend_from_slice(&a[..fade_start]);
+    for index in 0..overlap {
+        let position = (index + 1) as f32 / (overlap + 1) as f32;
+        let angle = position * std::f32::consts::FRAC_PI_2;
+        let sample = a[fade_start + index] * angle.cos() + b[index] * angle.sin();
+        joined.push(sample.clamp(-1.0, 1.0));
+    }
+    joined.extend_from_slice(&b[overlap..]);
+    joined
+}
@@ -1,12 +1,20 @@
-use super::common::{ArpeggioDirection, AudioPlayError, Playable, Synth};
+use super::common::{
+    ArpeggioDirection, AudioPlayError, DrumKind, DynamicsCurves, Playable, SweepShape, Synth,
+    Tuning,
+};
+use super::mixer::mix_buffers;
 use super::processor::{AudioProcessor, SynthRc};
 use super::wavetable::WavetableOscillator;
-use crate::common::AudioDuration;
+use crate::common::{AudioDuration, Beat, Fraction, InputError};
+use crate::melody::Phrase;
 use crate::midi::MIDI;
+use crate::note::Note;
+use crate::scale::Scale;
 use crate::track::Event;
 use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
 use rodio::{OutputStream, Sink, Source};
 use std::cmp::min;
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
@@ -24,17 +32,54 @@ pub enum BitsPerSample {
     TWENTYFOUR = 24,
 }
 
+/// A structure containing the metadata that can be stored in the LIST/INFO chunk of an exported WAV
+/// file. Any fields which are [`None`] are not written to the file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WavMetadata {
+    /// The title of the audio, which is stored in the INAM field.
+    pub title: Option<String>,
+    /// The artist of the audio, which is stored in the IART field.
+    pub artist: Option<String>,
+    /// A comment about the audio, which is stored in the ICMT field.
+    pub comment: Option<String>,
+}
+
+/// A structure containing a snapshot of the settings of an [`AudioPlayer`], which can be used to
+/// render audio with the same settings at a later time. The synthesizer of the audio player is not
+/// included in the snapshot.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlayerConfig {
+    /// The tempo of the audio player in beats per minute.
+    pub tempo: f32,
+    /// The speed of the audio player, which multiplies the tempo that beats are played at.
+    pub speed: f32,
+    /// The fraction of the duration of each pushed note that the note sounds for.
+    pub gate: f32,
+    /// The master volume of the audio player between 0.0 and 1.0.
+    pub volume: f32,
+    /// The sample rate of the audio player in hertz.
+    pub sample_rate: u32,
+    /// The threshold of the soft limiter of the audio player, or [`None`] if it is disabled.
+    pub limiter_threshold: Option<f32>,
+    /// The reference frequency in hertz of the note A4 that the audio player tunes to.
+    pub base_frequency: f32,
+    /// The tuning system of the audio player.
+    pub tuning: Tuning,
+}
+
 #[derive(Clone, Debug)]
 struct PlayableAudio {
     audio: Vec<f32>,
     index: usize,
+    sample_rate: u32,
 }
 
 impl PlayableAudio {
-    pub fn new(audio: &[f32]) -> Self {
+    pub fn new(audio: &[f32], sample_rate: u32) -> Self {
         Self {
             audio: Vec::from(audio),
             index: 0,
+            sample_rate,
         }
     }
 }
@@ -55,7 +100,7 @@ impl Source for PlayableAudio {
     }
 
     fn sample_rate(&self) -> u32 {
-        44100
+        self.sample_rate
     }
 
     fn current_frame_len(&self) -> Option<usize> {
@@ -67,12 +112,21 @@ impl Source for PlayableAudio {
     }
 }
 
+struct AudioOutput {
+    sink: Sink,
+    _stream: OutputStream,
+}
+
 /// A structure which can be used to play audio through the speakers of the current machine or to
 /// export audio into a WAV file.
 pub struct AudioPlayer {
     tempo: f32,
-    sink: Sink,
-    _stream: OutputStream,
+    speed: f32,
+    gate: f32,
+    base_frequency: f32,
+    tuning: Tuning,
+    dynamics_curve: fn(f32) -> f32,
+    output: Option<AudioOutput>,
     processor: AudioProcessor,
     synth_ref: SynthRc,
     buffer: Vec<f32>,
@@ -95,17 +149,47 @@ impl AudioPlayer {
                 message: "sink could not be created",
             });
         }
+        let mut player = Self::new_offline();
+        player.output = Some(AudioOutput {
+            sink: sink_result.unwrap(),
+            _stream,
+        });
+        Ok(player)
+    }
+
+    /// Creates an audio player which is not connected to any audio device, which can be used to
+    /// render and export audio on machines without a sound card. Calling [`AudioPlayer::play`] or
+    /// [`AudioPlayer::play_now`] on an offline audio player returns an [`AudioPlayError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    /// assert!(player.buffer_len() > 0);
+    /// assert!(player.play().is_err());
+    /// ```
+    pub fn new_offline() -> Self {
         let mut processor = AudioProcessor::new();
         let oscillator = WavetableOscillator::default();
         let default_synth_ref = processor.register_synth(Box::new(oscillator));
-        Ok(Self {
+        Self {
             tempo: 120.0,
-            sink: sink_result.unwrap(),
-            _stream,
+            speed: 1.0,
+            gate: 1.0,
+            base_frequency: 440.0,
+            tuning: Tuning::Equal,
+            dynamics_curve: DynamicsCurves::LINEAR,
+            output: None,
             processor,
             synth_ref: default_synth_ref,
             buffer: Vec::new(),
-        })
+        }
     }
 
     /// Sets the synthesizer that will be used to play the audio. If this function is never called a
@@ -139,6 +223,355 @@ impl AudioPlayer {
         self.tempo = tempo;
     }
 
+    /// Sets the speed of the audio player, which multiplies the tempo that beats and MIDI items are
+    /// played at without changing the tempo itself. A speed of 1.0 plays at the tempo, which is the
+    /// default, while a speed of 2.0 plays twice as fast. Durations that are not measured in beats
+    /// are not affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `speed`: An [`f32`] representing the speed of the audio player, which must be positive.
+    ///   Values which are not positive are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.set_speed(2.0);
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    /// assert_eq!(player.buffer_len(), 44100);
+    /// ```
+    pub fn set_speed(&mut self, speed: f32) {
+        if speed > 0.0 {
+            self.speed = speed;
+        }
+    }
+
+    /// Returns the speed of the audio player, which multiplies the tempo that beats are played at.
+    pub fn get_speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the tuning system that the audio player uses to calculate the frequencies of notes.
+    /// Twelve-tone equal temperament is currently the only tuning system and the default.
+    ///
+    /// # Parameters
+    ///
+    /// - `tuning`: A [`Tuning`] enum representing the tuning system to use.
+    pub fn set_tuning(&mut self, tuning: Tuning) {
+        self.tuning = tuning;
+    }
+
+    /// Returns a [`Tuning`] enum representing the tuning system of the audio player.
+    pub fn get_tuning(&self) -> Tuning {
+        self.tuning
+    }
+
+    /// Sets the gate of the audio player, which is the fraction of the duration of each pushed
+    /// note that the note sounds for before it is followed by a rest for the remainder of the
+    /// duration. A gate of 1.0 plays each note for its full duration, which is the default, while
+    /// small gates produce staccato notes.
+    ///
+    /// # Parameters
+    ///
+    /// - `gate`: An [`f32`] representing the gate of the audio player, which must be between 0.0
+    ///   and 1.0. Gates outside of this range are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.set_gate(0.25);
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
+    /// let buffer = player.render();
+    /// assert_eq!(buffer[buffer.len() - 1], 0.0);
+    /// ```
+    pub fn set_gate(&mut self, gate: f32) {
+        self.gate = gate.clamp(0.0, 1.0);
+    }
+
+    /// Sets the reference frequency of the note A4 that the audio player tunes to, which is 440
+    /// hertz by default. The notes, chords, scales and MIDI items that are pushed or played
+    /// afterwards are played at this reference instead of the reference of their notes, such as
+    /// A4 = 432 hertz or the baroque pitch of A4 = 415 hertz, using
+    /// [`Note::get_frequency_at_reference`]. Frequencies given directly in hertz, including the
+    /// frequencies of sweeps, are played unchanged. The audio that has already been queued is not
+    /// modified.
+    ///
+    /// # Parameters
+    ///
+    /// - `base_frequency`: An [`f32`] representing the frequency in hertz of the note A4, which
+    ///   must be positive. Values which are not positive are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.set_base_frequency(415.0);
+    /// assert_eq!(player.get_base_frequency(), 415.0);
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
+    /// ```
+    pub fn set_base_frequency(&mut self, base_frequency: f32) {
+        if base_frequency > 0.0 {
+            self.base_frequency = base_frequency;
+        }
+    }
+
+    /// Returns the reference frequency in hertz of the note A4 that the audio player tunes to.
+    pub fn get_base_frequency(&self) -> f32 {
+        self.base_frequency
+    }
+
+    /// Sets the dynamics curve of the audio player, which maps the velocity of the notes of MIDI
+    /// items to the amplitude they are played with. The curve receives the velocity normalized
+    /// between 0.0 and 1.0 and must return an amplitude between 0.0 and 1.0. The default curve is
+    /// [`DynamicsCurves::LINEAR`].
+    ///
+    /// # Parameters
+    ///
+    /// - `curve`: The function used to map velocities to amplitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::DynamicsCurves;
+    /// use music_tools::audio::player::AudioPlayer;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// assert_eq!(player.get_velocity_amplitude(127), 1.0);
+    /// player.set_dynamics_curve(DynamicsCurves::EXPONENTIAL);
+    /// assert!(player.get_velocity_amplitude(64) < 0.5);
+    /// ```
+    pub fn set_dynamics_curve(&mut self, curve: fn(f32) -> f32) {
+        self.dynamics_curve = curve;
+    }
+
+    /// Returns the amplitude between 0.0 and 1.0 that a note with a given MIDI velocity is played
+    /// with according to the dynamics curve of the audio player.
+    ///
+    /// # Parameters
+    ///
+    /// - `velocity`: A [`u8`] between 0 and 127 representing the velocity of the note.
+    pub fn get_velocity_amplitude(&self, velocity: u8) -> f32 {
+        (self.dynamics_curve)(velocity.min(127) as f32 / 127.0).clamp(0.0, 1.0)
+    }
+
+    /// Sets the sample rate in hertz used to render, play and export the audio of the audio
+    /// player. The audio that has already been queued is not resampled, so the sample rate should
+    /// be set before pushing any audio. The default sample rate is 44100 Hz.
+    ///
+    /// # Parameters
+    ///
+    /// - `sample_rate`: A [`u32`] representing the new sample rate in hertz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.set_sample_rate(48000);
+    /// assert_eq!(player.get_sample_rate(), 48000);
+    /// ```
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.processor.set_sample_rate(sample_rate);
+    }
+
+    /// Returns the sample rate in hertz used to render, play and export the audio of the audio
+    /// player.
+    pub fn get_sample_rate(&self) -> u32 {
+        self.processor.get_sample_rate()
+    }
+
+    /// Resamples the audio that has been queued to a new sample rate using linear interpolation,
+    /// and sets the sample rate of the audio player to the new sample rate so that any audio pushed
+    /// or exported afterwards uses it.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_rate`: A [`u32`] representing the new sample rate in hertz, which must be positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    /// assert_eq!(player.buffer_len(), 88200);
+    /// player.resample(22050);
+    /// assert_eq!(player.get_sample_rate(), 22050);
+    /// assert_eq!(player.buffer_len(), 44100);
+    /// ```
+    pub fn resample(&mut self, new_rate: u32) {
+        let old_rate = self.processor.get_sample_rate();
+        if new_rate == 0 || new_rate == old_rate {
+            return;
+        }
+        let ratio = old_rate as f64 / new_rate as f64;
+        let new_len = (self.buffer.len() as f64 / ratio).round() as usize;
+        let mut resampled = Vec::with_capacity(new_len);
+        for index in 0..new_len {
+            let position = index as f64 * ratio;
+            let current_index = position as usize;
+            let lerp_frac = (position - current_index as f64) as f32;
+            let current_value = self.buffer.get(current_index).copied().unwrap_or(0.0);
+            let next_value = self
+                .buffer
+                .get(current_index + 1)
+                .copied()
+                .unwrap_or(current_value);
+            resampled.push(current_value + lerp_frac * (next_value - current_value));
+        }
+        self.buffer = resampled;
+        self.processor.set_sample_rate(new_rate);
+    }
+
+    /// Changes the duration of the audio that has been queued by a given factor without changing
+    /// its pitch, using an overlap-add of windowed frames of 50 milliseconds. A factor greater than
+    /// 1.0 makes the audio longer and a factor smaller than 1.0 makes it shorter, while a factor of
+    /// 1.0 leaves the audio unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `factor`: An [`f32`] representing the factor to multiply the duration of the audio by,
+    ///   which must be positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    /// assert_eq!(player.buffer_len(), 88200);
+    /// player.time_stretch(2.0);
+    /// assert_eq!(player.buffer_len(), 176400);
+    /// ```
+    pub fn time_stretch(&mut self, factor: f32) {
+        if factor <= 0.0 || factor == 1.0 || self.buffer.is_empty() {
+            return;
+        }
+        let frame_len = (self.processor.get_sample_rate() as usize / 20).max(2);
+        let synthesis_hop = frame_len / 2;
+        let window: Vec<f32> = (0..frame_len)
+            .map(|index| {
+                let phase = index as f32 / frame_len as f32;
+                0.5 - 0.5 * f32::cos(2.0 * std::f32::consts::PI * phase)
+            })
+            .collect();
+        let new_len = (self.buffer.len() as f32 * factor).round() as usize;
+        let mut stretched = vec![0.0; new_len];
+        let mut weights = vec![0.0; new_len];
+        let mut output_start = 0;
+        while output_start < new_len {
+            let input_start = (output_start as f32 / factor).round() as usize;
+            for (offset, window_value) in window.iter().enumerate() {
+                let output_index = output_start + offset;
+                if output_index >= new_len {
+                    break;
+                }
+                let sample = self
+                    .buffer
+                    .get(input_start + offset)
+                    .copied()
+                    .unwrap_or(0.0);
+                stretched[output_index] += sample * window_value;
+                weights[output_index] += window_value;
+            }
+            output_start += synthesis_hop;
+        }
+        for (sample, weight) in stretched.iter_mut().zip(weights) {
+            if weight > f32::EPSILON {
+                *sample /= weight;
+            }
+        }
+        self.buffer = stretched;
+    }
+
+    /// Reverses the audio that has been queued, so that it is played backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.mix_in(&[0.1, 0.2, 0.3], 1.0);
+    /// player.reverse();
+    /// assert_eq!(player.render(), vec![0.3, 0.2, 0.1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.buffer.reverse();
+    }
+
+    /// Returns a [`PlayerConfig`] with a snapshot of the current settings of the audio player.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.set_tempo(90.0);
+    /// let config = player.get_config();
+    /// assert_eq!(config.tempo, 90.0);
+    /// assert_eq!(config.sample_rate, 44100);
+    /// ```
+    pub fn get_config(&self) -> PlayerConfig {
+        PlayerConfig {
+            tempo: self.tempo,
+            speed: self.speed,
+            gate: self.gate,
+            volume: self.processor.get_volume(),
+            sample_rate: self.processor.get_sample_rate(),
+            limiter_threshold: self.processor.get_limiter_threshold(),
+            base_frequency: self.base_frequency,
+            tuning: self.tuning,
+        }
+    }
+
+    /// Applies all the settings stored in a [`PlayerConfig`] to the audio player. The audio that
+    /// has already been queued is not modified.
+    ///
+    /// # Parameters
+    ///
+    /// - `config`: A reference to the [`PlayerConfig`] with the settings to apply.
+    pub fn apply_config(&mut self, config: &PlayerConfig) {
+        self.set_tempo(config.tempo);
+        self.set_speed(config.speed);
+        self.set_gate(config.gate);
+        self.set_volume(config.volume);
+        self.set_sample_rate(config.sample_rate);
+        self.set_base_frequency(config.base_frequency);
+        self.set_tuning(config.tuning);
+        match config.limiter_threshold {
+            Some(threshold) => self.processor.set_limiter(threshold),
+            None => self.processor.disable_limiter(),
+        }
+    }
+
     /// Returns a reference to the [`AudioProcessor`] used by the audio player.
     pub fn get_processor(&self) -> &AudioProcessor {
         &self.processor
@@ -149,15 +582,22 @@ impl AudioPlayer {
     /// # Parameters
     ///
     /// - `playable`: The audio to be played which must implement the [`Playable`] trait.
-    /// - `duration`: A duration representing how long the audio will be played for. This duration
-    ///   must implement the [`AudioDuration`] trait.
+    /// - `duration`: A duration representing how long the audio will be played for, including the
+    ///   rest added by the gate of the audio player. This duration must implement the
+    ///   [`AudioDuration`] trait.
     pub fn push(&mut self, playable: &impl Playable, duration: &impl AudioDuration) {
-        for frequency in playable.get_frequencies() {
+        let total_duration = duration.get_duration(self.tempo * self.speed);
+        let gated_duration = total_duration.mul_f32(self.gate);
+        for frequency in playable.get_frequencies_at_reference(self.base_frequency) {
             self.processor.start_frequency(frequency, &self.synth_ref);
         }
-        let mut audio_vec = self.processor.render(duration.get_duration(self.tempo));
+        let mut audio_vec = self.processor.render(gated_duration);
         self.processor.stop_all_frequencies();
         self.buffer.append(&mut audio_vec);
+        if gated_duration < total_duration {
+            let mut rest_vec = self.processor.render(total_duration - gated_duration);
+            self.buffer.append(&mut rest_vec);
+        }
     }
 
     /// Pushes a rest note to the queue of audio to be played.
@@ -167,7 +607,9 @@ impl AudioPlayer {
     /// - `duration`: A duration representing how long the rest will last for. This duration must
     ///   implement the [`AudioDuration`] trait.
     pub fn push_rest(&mut self, duration: &impl AudioDuration) {
-        let mut audio_vec = self.processor.render(duration.get_duration(self.tempo));
+        let mut audio_vec = self
+            .processor
+            .render(duration.get_duration(self.tempo * self.speed));
         self.buffer.append(&mut audio_vec);
     }
 
@@ -189,35 +631,187 @@ impl AudioPlayer {
         direction: ArpeggioDirection,
         repetitions: usize,
     ) {
-        let frequencies = playable.get_frequencies();
-        let mut updown_ascending: bool = true;
-        let mut current_index = match direction {
-            ArpeggioDirection::Up => 0,
-            ArpeggioDirection::Down => frequencies.len() - 1,
-            ArpeggioDirection::UpDown => 0,
-        };
-        for _ in 0..repetitions {
-            let curr_frequency = frequencies[current_index];
-            self.push(&curr_frequency, duration);
-            current_index = match direction {
-                ArpeggioDirection::Up => (current_index + 1).rem_euclid(frequencies.len()),
-                ArpeggioDirection::Down => {
-                    (current_index as isize - 1).rem_euclid(frequencies.len() as isize) as usize
+        let frequencies = playable.get_frequencies_at_reference(self.base_frequency);
+        for index in direction.get_indices(frequencies.len(), repetitions) {
+            self.push(&frequencies[index], duration);
+        }
+    }
+
+    /// Pushes each of the notes of a scale to the queue of audio to be played one after another in
+    /// ascending order, starting from a given tonic.
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: A reference to the [`Scale`] to be played.
+    /// - `tonic`: A reference to the [`Note`] that the scale starts on.
+    /// - `duration`: A duration representing how long each note of the scale will last for. This
+    ///   duration must implement the [`AudioDuration`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::{Beat, PentatonicType, ScaleType};
+    /// use music_tools::note::Note;
+    /// use music_tools::scale::Scale;
+    /// use std::str::FromStr;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// let dorian = Scale::try_new(ScaleType::Dorian, PentatonicType::None).unwrap();
+    /// player.push_scale(&dorian, &Note::from_str("D4").unwrap(), &Beat::EIGHTH);
+    /// assert_eq!(player.buffer_len(), 7 * 11025);
+    /// ```
+    pub fn push_scale(&mut self, scale: &Scale, tonic: &Note, duration: &impl AudioDuration) {
+        let num_notes = scale.get_intervals().len() - 1;
+        for note in scale.note_iter(*tonic).take(num_notes) {
+            self.push(&note, duration);
+        }
+    }
+
+    /// Pushes a frequency sweep to the queue of audio to be played, which continuously changes the
+    /// frequency played by the current synthesizer from one frequency to another.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_frequency`: An [`f32`] representing the frequency in hertz at the start of the
+    ///   sweep.
+    /// - `end_frequency`: An [`f32`] representing the frequency in hertz at the end of the sweep.
+    /// - `duration`: A duration representing how long the sweep will last for. This duration must
+    ///   implement the [`AudioDuration`] trait.
+    /// - `shape`: A [`SweepShape`] enum representing how the frequency changes over time.
+    pub fn push_sweep(
+        &mut self,
+        start_frequency: f32,
+        end_frequency: f32,
+        duration: &impl AudioDuration,
+        shape: SweepShape,
+    ) {
+        let samples = (duration.get_duration(self.tempo * self.speed).as_secs_f64()
+            * self.processor.get_sample_rate() as f64) as usize;
+        let last_index = samples.saturating_sub(1).max(1);
+        let mut current_frequency = start_frequency;
+        self.processor
+            .start_frequency(current_frequency, &self.synth_ref);
+        for index in 0..samples {
+            let progress = index as f32 / last_index as f32;
+            let next_frequency = shape.get_frequency(start_frequency, end_frequency, progress);
+            self.processor
+                .change_frequency(current_frequency, next_frequency, &self.synth_ref);
+            current_frequency = next_frequency;
+            self.buffer.push(self.processor.get_current_sample());
+            self.processor.advance_sample();
+        }
+        self.processor.stop_all_frequencies();
+    }
+
+    /// Pushes a percussion hit to the queue of audio to be played. The hit is synthesized directly
+    /// and does not use the current synthesizer.
+    ///
+    /// # Parameters
+    ///
+    /// - `kind`: A [`DrumKind`] enum representing the kind of percussion hit to play.
+    /// - `duration`: A duration representing how long the hit will last for. This duration must
+    ///   implement the [`AudioDuration`] trait.
+    pub fn push_drum_hit(&mut self, kind: DrumKind, duration: &impl AudioDuration) {
+        let mut audio_vec = kind.render(
+            duration.get_duration(self.tempo * self.speed),
+            self.processor.get_sample_rate(),
+        );
+        self.buffer.append(&mut audio_vec);
+    }
+
+    /// Pushes a metronome click track to the queue of audio to be played, where the clicks of each
+    /// bar are split into groups and the first click of each group is accented. This allows odd
+    /// and compound meters such as 7/8 to be counted as groups of 3, 2 and 2 clicks. The function
+    /// returns an [`InputError`] if the groups are empty, contain a group of zero clicks, or if the
+    /// total amount of clicks in the groups does not fill a bar of the time signature.
+    ///
+    /// # Parameters
+    ///
+    /// - `bars`: The amount of bars to push.
+    /// - `time_signature`: A [`Fraction`] representing the time signature of each bar.
+    /// - `subdivision`: A [`Beat`] representing the duration of each click.
+    /// - `groups`: A slice with the amount of clicks in each group of a bar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::{Beat, Fraction};
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player
+    ///     .push_metronome_grouped(2, Fraction::new(7, 8), Beat::EIGHTH, &[3, 2, 2])
+    ///     .unwrap();
+    /// assert!(player
+    ///     .push_metronome_grouped(1, Fraction::new(7, 8), Beat::EIGHTH, &[3, 3])
+    ///     .is_err());
+    /// ```
+    pub fn push_metronome_grouped(
+        &mut self,
+        bars: usize,
+        time_signature: Fraction,
+        subdivision: Beat,
+        groups: &[usize],
+    ) -> Result<(), InputError> {
+        if groups.is_empty() || groups.contains(&0) {
+            return Err(InputError {
+                message: "the metronome groups must contain at least one click each",
+            });
+        }
+        let clicks: usize = groups.iter().sum();
+        if clicks * time_signature.get_denominator() as usize * subdivision.get_numerator() as usize
+            != time_signature.get_numerator() as usize * subdivision.get_denominator() as usize
+        {
+            return Err(InputError {
+                message: "the metronome groups must add up to the amount of clicks in a bar",
+            });
+        }
+        let click = DrumKind::HiHat.render(
+            subdivision.get_duration(self.tempo * self.speed),
+            self.processor.get_sample_rate(),
+        );
+        for _ in 0..bars {
+            for group in groups {
+                for index in 0..*group {
+                    let gain = if index == 0 { 1.0 } else { 0.5 };
+                    self.buffer.extend(click.iter().map(|sample| sample * gain));
                 }
-                ArpeggioDirection::UpDown => match updown_ascending {
-                    true => (current_index + 1).rem_euclid(frequencies.len()),
-                    false => {
-                        (current_index as isize - 1).rem_euclid(frequencies.len() as isize) as usize
-                    }
-                },
-            };
-            if !updown_ascending && current_index == 0 {
-                updown_ascending = true;
-            }
-            if updown_ascending && current_index == frequencies.len() - 1 {
-                updown_ascending = false;
             }
         }
+        Ok(())
+    }
+
+    /// Pushes each note of a phrase to the queue of audio to be played, using the tempo of the
+    /// rhythm of the phrase.
+    ///
+    /// # Parameters
+    ///
+    /// - `phrase`: A reference to the [`Phrase`] to be played.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::melody::Phrase;
+    /// use music_tools::note::Note;
+    /// use music_tools::rhythm::Rhythm;
+    /// use music_tools::common::Fraction;
+    /// use std::str::FromStr;
+    ///
+    /// let notes = ["G4", "G4", "A#4", "C5"].map(|name| Note::from_str(name).unwrap());
+    /// let rhythm = Rhythm::from_pattern(160.0, Fraction::new(5, 4), "q. q. q q").unwrap();
+    /// let phrase = Phrase::try_new(&notes, rhythm).unwrap();
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.push_phrase(&phrase);
+    /// ```
+    pub fn push_phrase(&mut self, phrase: &Phrase) {
+        let tempo = self.tempo;
+        self.tempo = phrase.get_rhythm().get_tempo();
+        for (note, beat) in phrase.iter() {
+            self.push(&note, &beat);
+        }
+        self.tempo = tempo;
     }
 
     /// Pushes a MIDI item onto the queue of audio to be played.
@@ -235,6 +829,34 @@ impl AudioPlayer {
         synth: impl Synth + Clone + 'static,
         custom_tempo: Option<f32>,
     ) {
+        self.push_midi_with_synths(midi, &[synth], &HashMap::new(), custom_tempo);
+    }
+
+    /// Pushes a MIDI item onto the queue of audio to be played, choosing the synthesizer of each
+    /// track from a list of synthesizers. If the program of a track is in the program map then the
+    /// synthesizer at the mapped index is used, otherwise the synthesizers are assigned to the
+    /// tracks in order, wrapping around if there are more tracks than synthesizers. The tracks
+    /// played by synthesizers with a lower latency than the others are delayed by the difference,
+    /// as reported by [`Synth::get_latency`], so that all of the tracks stay in sync.
+    ///
+    /// # Parameters
+    ///
+    /// - `midi`: A reference to the [`MIDI`] to be played.
+    /// - `synths`: A slice of synthesizers that will be used to play the tracks of the MIDI item,
+    ///   which must implement the [`Synth`] trait. If the slice is empty nothing is pushed.
+    /// - `program_map`: A [`HashMap`] from MIDI program numbers to indices of `synths`.
+    /// - `custom_tempo`: An [`Option<f32>`] which if defined changes the tempo of the MIDI item. If
+    ///   it is not defined then the original tempo of the MIDI item is used.
+    pub fn push_midi_with_synths(
+        &mut self,
+        midi: &MIDI,
+        synths: &[impl Synth + Clone + 'static],
+        program_map: &HashMap<u8, usize>,
+        custom_tempo: Option<f32>,
+    ) {
+        if synths.is_empty() {
+            return;
+        }
         let mut tracks = midi.get_tracks();
         if tracks.is_empty() {
             return;
@@ -242,21 +864,32 @@ impl AudioPlayer {
         if let Some(tempo) = custom_tempo {
             tracks[0].set_tempo(tempo)
         }
-        let tick_ms = tracks[0].get_tick_duration();
+        let tick_ms = tracks[0].get_tick_duration() / self.speed;
+        let mut synth_ref_vec: Vec<SynthRc> = Vec::new();
+        let mut latencies: Vec<Duration> = Vec::new();
+        for (track_index, track) in tracks.iter().enumerate() {
+            let synth_index = track
+                .get_synth_index(program_map)
+                .filter(|index| *index < synths.len())
+                .unwrap_or(track_index % synths.len());
+            let oscillator = synths[synth_index].clone();
+            latencies.push(oscillator.get_latency());
+            let synth_ref = self.processor.register_synth(Box::new(oscillator));
+            synth_ref_vec.push(synth_ref);
+        }
+        let max_latency = latencies.iter().max().copied().unwrap_or(Duration::ZERO);
         let mut pending_event_tuples: Vec<(Event, u64, usize)> = Vec::new();
         for (track_index, track) in &mut tracks.iter_mut().enumerate() {
             let first_event_option = track.get_next_event();
             if let Some(first_event) = first_event_option {
-                let event_tuple = (first_event, first_event.get_delta_ticks(), track_index);
-                pending_event_tuples.push(event_tuple);
+                //Synthesizers with a lower latency start later to stay in sync with the others
+                let compensation = max_latency - latencies[track_index];
+                let compensation_ticks =
+                    (compensation.as_secs_f32() * 1000.0 / tick_ms).round() as u64;
+                let wait_ticks = first_event.get_delta_ticks() + compensation_ticks;
+                pending_event_tuples.push((first_event, wait_ticks, track_index));
             }
         }
-        let mut synth_ref_vec: Vec<SynthRc> = Vec::new();
-        for _ in tracks.iter() {
-            let oscillator = synth.clone();
-            let synth_ref = self.processor.register_synth(Box::new(oscillator));
-            synth_ref_vec.push(synth_ref);
-        }
         loop {
             let mut next_event_tuples: Vec<(Event, u64, usize)> = Vec::new();
             let mut min_wait_ticks = u64::MAX;
@@ -268,11 +901,21 @@ impl AudioPlayer {
                 let synth = &synth_ref_vec[track_index];
                 while wait_time == 0 {
                     if current_event.is_active() {
-                        self.processor
-                            .start_frequency(current_event.get_note().get_frequency(), synth);
+                        let amplitude = self.get_velocity_amplitude(current_event.get_velocity());
+                        self.processor.start_frequency_with_amplitude(
+                            current_event
+                                .get_note()
+                                .get_frequency_at_reference(self.base_frequency),
+                            amplitude,
+                            synth,
+                        );
                     } else {
-                        self.processor
-                            .stop_frequency(current_event.get_note().get_frequency(), synth);
+                        self.processor.release_frequency(
+                            current_event
+                                .get_note()
+                                .get_frequency_at_reference(self.base_frequency),
+                            synth,
+                        );
                     }
                     let next_event_option = &mut tracks[track_index].get_next_event();
                     if next_event_option.is_none() {
@@ -292,24 +935,88 @@ impl AudioPlayer {
             let mut audio_vec = self.processor.render(Duration::from_millis(
                 (tick_ms * (min_wait_ticks as f32)) as u64,
             ));
-            self.processor.stop_all_frequencies();
             self.buffer.append(&mut audio_vec);
             for event in &mut next_event_tuples {
                 *event = (event.0, event.1 - min_wait_ticks, event.2);
             }
             pending_event_tuples = next_event_tuples;
         }
+        self.processor.stop_all_frequencies();
         for mut track in tracks {
             track.reset_tracker();
         }
     }
 
-    /// Starts playing all the audio in the queue through the current speaker.
-    pub fn play(&self) {
-        let audio = PlayableAudio::new(&self.buffer);
-        self.sink.append(audio);
-        self.sink.play();
-        self.sink.sleep_until_end();
+    /// Mixes a buffer of samples into the audio that has been queued, starting from the first
+    /// queued sample. If the buffer is longer than the queued audio then the queue is extended.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A slice of [`f32`] containing the samples to mix into the queue.
+    /// - `gain`: An [`f32`] representing the gain to apply to the samples before mixing them.
+    pub fn mix_in(&mut self, other: &[f32], gain: f32) {
+        self.buffer = mix_buffers(&[self.buffer.clone(), other.to_vec()], &[1.0, gain]);
+    }
+
+    /// Starts playing all the audio in the queue through the current speaker and waits for it to
+    /// finish. A [`Result`] is returned which can be an [`AudioPlayError`] if the audio player is
+    /// offline.
+    pub fn play(&self) -> Result<(), AudioPlayError> {
+        let output = self.get_output()?;
+        let audio = PlayableAudio::new(&self.buffer, self.processor.get_sample_rate());
+        output.sink.append(audio);
+        output.sink.play();
+        output.sink.sleep_until_end();
+        Ok(())
+    }
+
+    /// Renders playable audio and starts playing it immediately through the current speaker without
+    /// waiting for it to finish. The audio is not added to the queue of audio to be played, so any
+    /// audio that has already been queued is left untouched. A [`Result`] is returned which can be
+    /// an [`AudioPlayError`] if the audio player is offline.
+    ///
+    /// # Parameters
+    ///
+    /// - `playable`: The audio to be played which must implement the [`Playable`] trait.
+    /// - `duration`: A duration representing how long the audio will play for. This duration must
+    ///   implement the [`AudioDuration`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// if let Ok(mut player) = AudioPlayer::try_new() {
+    ///     player.play_now(&Note::from_str("C4").unwrap(), &Beat::QUARTER).unwrap();
+    /// }
+    /// ```
+    pub fn play_now(
+        &mut self,
+        playable: &impl Playable,
+        duration: &impl AudioDuration,
+    ) -> Result<(), AudioPlayError> {
+        self.get_output()?;
+        for frequency in playable.get_frequencies_at_reference(self.base_frequency) {
+            self.processor.start_frequency(frequency, &self.synth_ref);
+        }
+        let audio_vec = self
+            .processor
+            .render(duration.get_duration(self.tempo * self.speed));
+        self.processor.stop_all_frequencies();
+        let output = self.get_output()?;
+        let audio = PlayableAudio::new(&audio_vec, self.processor.get_sample_rate());
+        output.sink.append(audio);
+        output.sink.play();
+        Ok(())
+    }
+
+    fn get_output(&self) -> Result<&AudioOutput, AudioPlayError> {
+        self.output.as_ref().ok_or(AudioPlayError {
+            message: "the audio player is offline",
+        })
     }
 
     /// Clears all the audio that has been queued.
@@ -322,6 +1029,58 @@ impl AudioPlayer {
         self.buffer.clone()
     }
 
+    /// Renders the audio that has been queued into a [`Vec<i8>`], scaling each sample to the full
+    /// range of an 8 bit signed integer. Samples outside of the range -1.0 to 1.0 are clamped.
+    pub fn render_i8(&self) -> Vec<i8> {
+        self.buffer
+            .iter()
+            .map(|sample| (127.5 * sample - 0.5) as i8)
+            .collect()
+    }
+
+    /// Renders the audio that has been queued into a [`Vec<i16>`], using the same scaling as the
+    /// 16 bit samples written by [`AudioPlayer::export_wav`]. Samples outside of the range -1.0 to
+    /// 1.0 are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::player::AudioPlayer;
+    /// use music_tools::common::Beat;
+    ///
+    /// let mut player = AudioPlayer::new_offline();
+    /// player.push_rest(&Beat::QUARTER);
+    /// assert!(player.render_i16().iter().all(|sample| *sample == 0));
+    /// ```
+    pub fn render_i16(&self) -> Vec<i16> {
+        self.buffer
+            .iter()
+            .map(|sample| (32767.5 * sample - 0.5) as i16)
+            .collect()
+    }
+
+    /// Renders the audio that has been queued into a [`Vec<i32>`] containing 24 bit samples, using
+    /// the same scaling as the 24 bit samples written by [`AudioPlayer::export_wav`]. Samples
+    /// outside of the range -1.0 to 1.0 are clamped.
+    pub fn render_i24(&self) -> Vec<i32> {
+        self.buffer
+            .iter()
+            .map(|sample| (8388607.5 * sample.clamp(-1.0, 1.0) - 0.5) as i32)
+            .collect()
+    }
+
+    /// Returns the amount of samples of audio that have been queued.
+    pub fn buffer_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns a [`Duration`] representing how long the audio that has been queued will take to
+    /// play, which is calculated from the amount of queued samples and the sample rate of the audio
+    /// processor.
+    pub fn total_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.buffer.len() as f64 / self.processor.get_sample_rate() as f64)
+    }
+
     /// Exports the audio that has been queued to a WAV file.
     ///
     /// # Parameters
@@ -333,14 +1092,79 @@ impl AudioPlayer {
         &self,
         path: &str,
         bits_per_sample: BitsPerSample,
+    ) -> Result<(), Box<dyn Error>> {
+        self.export_wav_tagged(path, bits_per_sample, &WavMetadata::default())
+    }
+
+    /// Exports the audio that has been queued to a WAV file with a LIST/INFO chunk containing the
+    /// given metadata. If none of the fields of the metadata are set the chunk is omitted and the
+    /// file is identical to the one produced by [`AudioPlayer::export_wav`].
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: A string representing the path of the WAV file to generate.
+    /// - `bits_per_sample`: A [`BitsPerSample`] enum representing the amount of bits per sample to
+    ///   be stored in the WAV file.
+    /// - `metadata`: A reference to a [`WavMetadata`] containing the title, artist and comment to
+    ///   store in the WAV file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use music_tools::audio::player::{AudioPlayer, BitsPerSample, WavMetadata};
+    ///
+    /// if let Ok(player) = AudioPlayer::try_new() {
+    ///     let metadata = WavMetadata {
+    ///         title: Some(String::from("Scale")),
+    ///         artist: Some(String::from("Music Tools")),
+    ///         comment: None,
+    ///     };
+    ///     player
+    ///         .export_wav_tagged("scale.wav", BitsPerSample::SIXTEEN, &metadata)
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn export_wav_tagged(
+        &self,
+        path: &str,
+        bits_per_sample: BitsPerSample,
+        metadata: &WavMetadata,
     ) -> Result<(), Box<dyn Error>> {
         const CHANNELS: u16 = 1; //Mono audio
         let subchunk2_len: u32 =
             self.buffer.len() as u32 * CHANNELS as u32 * bits_per_sample as u32 / 8;
+        let mut list_chunk: Vec<u8> = Vec::new();
+        for (id, field) in [
+            (0x494e414d, &metadata.title),   //"INAM"
+            (0x49415254, &metadata.artist),  //"IART"
+            (0x49434d54, &metadata.comment), //"ICMT"
+        ] {
+            if let Some(text) = field {
+                let text_len = text.len() as u32 + 1; //Null terminated
+                list_chunk.write_u32::<BigEndian>(id)?;
+                list_chunk.write_u32::<LittleEndian>(text_len)?;
+                list_chunk.write_all(text.as_bytes())?;
+                list_chunk.write_u8(0)?;
+                if text_len % 2 == 1 {
+                    list_chunk.write_u8(0)?; //Pad byte
+                }
+            }
+        }
+        let data_padding: u32 = if list_chunk.is_empty() {
+            0
+        } else {
+            subchunk2_len % 2
+        };
+        let list_chunk_len: u32 = if list_chunk.is_empty() {
+            0
+        } else {
+            12 + list_chunk.len() as u32
+        };
         let mut file = File::create(path)?;
         let mut file_buffer: Vec<u8> = Vec::new();
         file_buffer.write_u32::<BigEndian>(0x52494646)?; //"RIFF"
-        file_buffer.write_u32::<LittleEndian>(36 + subchunk2_len)?; //Chunk size
+        file_buffer
+            .write_u32::<LittleEndian>(36 + subchunk2_len + data_padding + list_chunk_len)?; //Chunk size
         file_buffer.write_u32::<BigEndian>(0x57415645)?; //"WAVE"
         file_buffer.write_u32::<BigEndian>(0x666d7420)?; //"fmt "
         file_buffer.write_u32::<LittleEndian>(16)?; //PCM mode
@@ -367,6 +1191,15 @@ impl AudioPlayer {
                 }
             }
         }
+        for _ in 0..data_padding {
+            file_buffer.write_u8(0)?; //Pad byte
+        }
+        if !list_chunk.is_empty() {
+            file_buffer.write_u32::<BigEndian>(0x4c495354)?; //"LIST"
+            file_buffer.write_u32::<LittleEndian>(4 + list_chunk.len() as u32)?;
+            file_buffer.write_u32::<BigEndian>(0x494e464f)?; //"INFO"
+            file_buffer.write_all(&list_chunk)?;
+        }
         file.write_all(&file_buffer)?;
         Ok(())
     }
@@ -1,4 +1,4 @@
-use super::common::Synth;
+use super::common::{MixingMode, Synth};
 use ordered_float::OrderedFloat;
 use std::cell::RefCell;
 use std::collections::HashSet;
@@ -15,6 +15,12 @@ pub struct AudioProcessor {
     current_sample: Option<f32>,
     sample_rate: u32,
     volume: f32,
+    limiter_threshold: Option<f32>,
+    headroom: f32,
+    ring_mod_frequency: f32,
+    ring_mod_mix: f32,
+    ring_mod_phase: f32,
+    mixing_mode: MixingMode,
 }
 
 impl AudioProcessor {
@@ -33,6 +39,113 @@ impl AudioProcessor {
         self.volume = volume.clamp(0.0, 1.0);
     }
 
+    /// Returns the master volume of the audio processor, which is a value between 0.0 and 1.0.
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Enables a soft limiter on the output of the audio processor. Samples with an amplitude
+    /// above the threshold are smoothly compressed so that they approach but never exceed the
+    /// headroom ceiling, which is 1.0 by default, instead of being abruptly clipped. The limiter is
+    /// disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `threshold`: An [`f32`] representing the amplitude above which samples are compressed as a
+    ///   proportion of the headroom ceiling, which must be between 0.0 and 1.0. Values outside of
+    ///   this range are clamped.
+    pub fn set_limiter(&mut self, threshold: f32) {
+        self.limiter_threshold = Some(threshold.clamp(0.0, 1.0));
+    }
+
+    /// Disables the soft limiter, so that samples outside of the valid range are clipped.
+    pub fn disable_limiter(&mut self) {
+        self.limiter_threshold = None;
+    }
+
+    /// Returns an [`Option<f32>`] containing the threshold of the soft limiter, or [`None`] if the
+    /// limiter is disabled.
+    pub fn get_limiter_threshold(&self) -> Option<f32> {
+        self.limiter_threshold
+    }
+
+    /// Sets the ceiling used to clip the output of the audio processor, so that no sample exceeds
+    /// the ceiling in magnitude and headroom is left for further processing. If the soft limiter
+    /// is enabled then samples are compressed towards the ceiling instead of being clipped. The
+    /// ceiling is 1.0 by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `ceiling`: An [`f32`] representing the highest amplitude of the output, which must be
+    ///   between 0.0 and 1.0. Values outside of this range are clamped.
+    pub fn set_headroom(&mut self, ceiling: f32) {
+        self.headroom = ceiling.clamp(0.0, 1.0);
+    }
+
+    /// Returns the ceiling used to clip the output of the audio processor, which is a value
+    /// between 0.0 and 1.0.
+    pub fn get_headroom(&self) -> f32 {
+        self.headroom
+    }
+
+    /// Enables ring modulation on the output of the audio processor, which multiplies the output
+    /// by a sine wave carrier to create metallic and bell-like sounds. The modulated signal is
+    /// mixed with the original signal, and a mix of 0.0 disables the effect, which is the default.
+    /// The phase of the carrier is reset every time this function is called.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: An [`f32`] representing the frequency of the carrier in hertz.
+    /// - `mix`: An [`f32`] representing the proportion of the modulated signal in the output, which
+    ///   must be between 0.0 and 1.0. Values outside of this range are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::processor::AudioProcessor;
+    ///
+    /// let mut processor = AudioProcessor::new();
+    /// processor.set_ring_mod(30.0, 0.5);
+    /// assert_eq!(processor.get_ring_mod(), (30.0, 0.5));
+    /// ```
+    pub fn set_ring_mod(&mut self, frequency: f32, mix: f32) {
+        self.ring_mod_frequency = frequency;
+        self.ring_mod_mix = mix.clamp(0.0, 1.0);
+        self.ring_mod_phase = 0.0;
+    }
+
+    /// Returns a tuple with the frequency of the ring modulation carrier in hertz and the
+    /// proportion of the modulated signal in the output.
+    pub fn get_ring_mod(&self) -> (f32, f32) {
+        (self.ring_mod_frequency, self.ring_mod_mix)
+    }
+
+    /// Sets how the samples of the registered synthesizers are mixed together. The default mode
+    /// is [`MixingMode::RmsNormalized`].
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: A [`MixingMode`] representing how the samples will be mixed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::MixingMode;
+    /// use music_tools::audio::processor::AudioProcessor;
+    ///
+    /// let mut processor = AudioProcessor::new();
+    /// processor.set_mixing_mode(MixingMode::Sum);
+    /// assert_eq!(processor.get_mixing_mode(), MixingMode::Sum);
+    /// ```
+    pub fn set_mixing_mode(&mut self, mode: MixingMode) {
+        self.mixing_mode = mode;
+    }
+
+    /// Returns the [`MixingMode`] used to mix the samples of the registered synthesizers.
+    pub fn get_mixing_mode(&self) -> MixingMode {
+        self.mixing_mode
+    }
+
     /// Returns an [`f32`] representing the current sample output of the audio processor. This
     /// sample will remain the same until the advance_sample() function is called.
     pub fn get_current_sample(&mut self) -> f32 {
@@ -46,7 +159,25 @@ impl AudioProcessor {
                 sample += synth_sample;
                 active_synths += 1;
             }
-            sample = (sample * self.volume / (active_synths as f32).sqrt()).clamp(-1.0, 1.0);
+            let normalization = match self.mixing_mode {
+                MixingMode::RmsNormalized => (active_synths as f32).sqrt(),
+                MixingMode::Sum => 1.0,
+                MixingMode::Average => active_synths as f32,
+            };
+            sample *= self.volume / normalization.max(1.0);
+            if self.ring_mod_mix > 0.0 {
+                let carrier = f32::sin(2.0 * std::f32::consts::PI * self.ring_mod_phase);
+                sample *= 1.0 - self.ring_mod_mix + self.ring_mod_mix * carrier;
+            }
+            if let Some(threshold) = self.limiter_threshold {
+                let threshold = threshold * self.headroom;
+                if sample.abs() > threshold {
+                    let knee = self.headroom - threshold;
+                    let excess = (sample.abs() - threshold) / knee;
+                    sample = sample.signum() * (threshold + knee * excess.tanh());
+                }
+            }
+            sample = sample.clamp(-self.headroom, self.headroom);
             self.current_sample = Some(sample);
             sample
         }
@@ -57,6 +188,8 @@ impl AudioProcessor {
         for (synth, _) in self.frequencies.iter_mut() {
             synth.borrow_mut().advance_sample(self.sample_rate);
         }
+        self.ring_mod_phase =
+            (self.ring_mod_phase + self.ring_mod_frequency / self.sample_rate as f32) % 1.0;
         self.current_sample = None;
     }
 
@@ -122,6 +255,31 @@ impl AudioProcessor {
         }
     }
 
+    /// Starts playing a specific frequency with a given amplitude on one of the registered
+    /// synthesizers.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: An [`f32`] representing the frequency in hertz that will be played.
+    /// - `amplitude`: An [`f32`] between 0.0 and 1.0 representing the amplitude of the frequency.
+    /// - `synth`: A reference to the [`SynthRc`] of the synthesizer that will play the frequency.
+    pub fn start_frequency_with_amplitude(
+        &mut self,
+        frequency: f32,
+        amplitude: f32,
+        synth: &SynthRc,
+    ) {
+        for (stored_synth, set) in self.frequencies.iter_mut() {
+            if Rc::ptr_eq(stored_synth, synth) {
+                stored_synth
+                    .borrow_mut()
+                    .add_voice_with_amplitude(frequency, amplitude);
+                set.insert(OrderedFloat(frequency));
+                return;
+            }
+        }
+    }
+
     /// Stops playing a specific frequency on one of the registered synthesizers.
     ///
     /// # Parameters
@@ -132,7 +290,54 @@ impl AudioProcessor {
         for (stored_synth, set) in self.frequencies.iter_mut() {
             if Rc::ptr_eq(stored_synth, synth) {
                 stored_synth.borrow_mut().remove_voice(frequency);
-                set.insert(OrderedFloat(frequency));
+                set.remove(&OrderedFloat(frequency));
+                return;
+            }
+        }
+    }
+
+    /// Releases a specific frequency on one of the registered synthesizers, which allows the
+    /// synthesizer to fade the frequency out instead of stopping it abruptly.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: An [`f32`] representing the frequency in hertz that will be released.
+    /// - `synth`: A reference to the [`SynthRc`] of the synthesizer that is playing the frequency.
+    pub fn release_frequency(&mut self, frequency: f32, synth: &SynthRc) {
+        for (stored_synth, set) in self.frequencies.iter_mut() {
+            if Rc::ptr_eq(stored_synth, synth) {
+                stored_synth.borrow_mut().release_voice(frequency);
+                set.remove(&OrderedFloat(frequency));
+                return;
+            }
+        }
+    }
+
+    /// Returns true if none of the registered synthesizers are producing sound, including any
+    /// frequencies that are still fading out after being released.
+    pub fn is_silent(&self) -> bool {
+        self.frequencies
+            .iter()
+            .all(|(synth, _)| synth.borrow().is_silent())
+    }
+
+    /// Changes a frequency that is being played on one of the registered synthesizers to a new
+    /// frequency without restarting it.
+    ///
+    /// # Parameters
+    ///
+    /// - `old_frequency`: An [`f32`] representing the frequency in hertz that is being played.
+    /// - `new_frequency`: An [`f32`] representing the frequency in hertz that will be played
+    ///   instead.
+    /// - `synth`: A reference to the [`SynthRc`] of the synthesizer that is playing the frequency.
+    pub fn change_frequency(&mut self, old_frequency: f32, new_frequency: f32, synth: &SynthRc) {
+        for (stored_synth, set) in self.frequencies.iter_mut() {
+            if Rc::ptr_eq(stored_synth, synth) {
+                stored_synth
+                    .borrow_mut()
+                    .change_voice(old_frequency, new_frequency);
+                set.remove(&OrderedFloat(old_frequency));
+                set.insert(OrderedFloat(new_frequency));
                 return;
             }
         }
@@ -140,11 +345,41 @@ impl AudioProcessor {
 
     /// Stops playing all frequencies across all the registered synthesizers.
     pub fn stop_all_frequencies(&mut self) {
-        for (synth, _) in self.frequencies.iter_mut() {
+        for (synth, set) in self.frequencies.iter_mut() {
             synth.borrow_mut().clear_voices();
+            set.clear();
         }
     }
 
+    /// Returns a vector with the frequencies that are currently being played across all the
+    /// registered synthesizers, sorted from lowest to highest. A frequency appears once for each
+    /// synthesizer that is playing it, and frequencies that have been stopped or released are not
+    /// included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::Waveforms;
+    /// use music_tools::audio::processor::AudioProcessor;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    ///
+    /// let mut processor = AudioProcessor::new();
+    /// let oscillator = WavetableOscillator::new(Waveforms::SINE_WAVE, 1.0, 128);
+    /// let synth = processor.register_synth(Box::new(oscillator));
+    /// processor.start_frequency(440.0, &synth);
+    /// processor.start_frequency(220.0, &synth);
+    /// assert_eq!(processor.active_frequencies(), vec![220.0, 440.0]);
+    /// ```
+    pub fn active_frequencies(&self) -> Vec<f32> {
+        let mut frequencies: Vec<f32> = self
+            .frequencies
+            .iter()
+            .flat_map(|(_, set)| set.iter().map(|frequency| frequency.into_inner()))
+            .collect();
+        frequencies.sort_by(|a, b| a.total_cmp(b));
+        frequencies
+    }
+
     /// Renders out a [`Vec<f32>`] of sample outputs of the audio processor for a given duration.
     ///
     /// # Parameters
@@ -169,6 +404,12 @@ impl Default for AudioProcessor {
             current_sample: None,
             sample_rate: 44100,
             volume: 1.0,
+            limiter_threshold: None,
+            headroom: 1.0,
+            ring_mod_frequency: 0.0,
+            ring_mod_mix: 0.0,
+            ring_mod_phase: 0.0,
+            mixing_mode: MixingMode::default(),
         }
     }
 }
@@ -1,35 +1,77 @@
-use super::common::{Synth, Waveforms};
+use super::common::{Interpolation, Synth, Waveforms};
 
 /**
  * A structure used to play a specific wavetable at a specific frequency.
  */
 #[derive(Copy, Clone, Debug)]
 struct WavetableVoice {
+    group: usize,
     frequency: f32,
+    detune_ratio: f32,
     table_index: f32,
+    amplitude: f32,
+    gain: f32,
+    released: bool,
 }
 
 impl WavetableVoice {
-    pub fn new(frequency: f32) -> Self {
+    pub fn new(group: usize, frequency: f32, detune_ratio: f32, amplitude: f32) -> Self {
         Self {
+            group,
             frequency,
+            detune_ratio,
             table_index: 0.0,
+            amplitude,
+            gain: 1.0,
+            released: false,
         }
     }
 
     pub fn add_delta_time(&mut self, table_size: usize, sample_rate: u32) {
-        let table_delta = self.frequency * table_size as f32 / sample_rate as f32;
+        let table_delta =
+            self.frequency * self.detune_ratio * table_size as f32 / sample_rate as f32;
         self.table_index += table_delta;
         self.table_index %= table_size as f32;
     }
 
+    pub fn get_group(&self) -> usize {
+        self.group
+    }
+
     pub fn get_frequency(&self) -> f32 {
         self.frequency
     }
 
+    pub fn set_frequency(&mut self, frequency: f32) {
+        self.frequency = frequency;
+    }
+
     pub fn get_table_index(&self) -> f32 {
         self.table_index
     }
+
+    pub fn get_amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    pub fn get_gain(&self) -> f32 {
+        self.gain
+    }
+
+    pub fn release(&mut self) {
+        self.released = true;
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+
+    pub fn add_release_time(&mut self, release_time: f32, sample_rate: u32) {
+        if self.released {
+            self.gain -= 1.0 / (release_time * sample_rate as f32).max(1.0);
+            self.gain = self.gain.max(0.0);
+        }
+    }
 }
 
 /// A structure which holds a wavetable oscillator.
@@ -61,7 +103,7 @@ impl WavetableVoice {
 /// if let Ok(mut player) = player {
 ///     player.set_synth(square_oscillator);
 ///     player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
-///     player.play();
+///     player.play().unwrap();
 /// }
 /// ```
 #[derive(Clone, Debug)]
@@ -69,6 +111,11 @@ pub struct WavetableOscillator {
     wavetable: Vec<f32>,
     voices: Vec<WavetableVoice>,
     volume: f32,
+    release_time: f32,
+    unison_voices: usize,
+    unison_detune: f32,
+    interpolation: Interpolation,
+    next_group: usize,
 }
 
 impl WavetableOscillator {
@@ -85,8 +132,8 @@ impl WavetableOscillator {
     ///   height of the wave at that time between -1 and 1.
     /// - `max_time`: This parameter scales the time variable that is passed to `wave_function`.
     /// - `wavetable_size`: The amount of points to store in the wavetable. The higher this value,
-    /// the higher the quality of the signal at the cost of a higher memory consumption. A value of
-    /// 128 is recommended.
+    ///   the higher the quality of the signal at the cost of a higher memory consumption. A value
+    ///   of 128 is recommended.
     pub fn new(wave_function: fn(f32) -> f32, max_time: f32, wavetable_size: usize) -> Self {
         let mut wavetable = Vec::with_capacity(wavetable_size);
         for i in 0..wavetable_size {
@@ -98,8 +145,208 @@ impl WavetableOscillator {
             wavetable,
             voices: Vec::new(),
             volume: 0.2,
+            release_time: 0.01,
+            unison_voices: 1,
+            unison_detune: 0.0,
+            interpolation: Interpolation::Linear,
+            next_group: 0,
+        }
+    }
+
+    /// Creates a new band-limited wavetable given a function of the height of the audio signal
+    /// between -1 and 1 with respect to time. The wavetable is built by adding together only the
+    /// first harmonics of the wave, which reduces the aliasing that bright waves such as square or
+    /// sawtooth waves produce when they are played at high frequencies, at the cost of a wave
+    /// shape which is not exactly the same as the original one. The harmonics are smoothed to
+    /// reduce ringing, and if the resulting wave exceeds the range between -1 and 1 it is scaled
+    /// down to fit within it.
+    ///
+    /// # Parameters
+    ///
+    /// - `wave_function`: The function used to generate the shape of the wave that will be played
+    ///   by the new wavetable. It must receive a parameter of type [`f32`] representing the time
+    ///   value of the wave between 0 and `max_time`, and it must return an [`f32`] representing the
+    ///   height of the wave at that time between -1 and 1.
+    /// - `max_time`: This parameter scales the time variable that is passed to `wave_function`.
+    /// - `wavetable_size`: The amount of points to store in the wavetable. A value of 128 is
+    ///   recommended.
+    /// - `max_harmonics`: The amount of harmonics of the wave to keep, which cannot exceed half of
+    ///   the size of the wavetable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::Waveforms;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    ///
+    /// let oscillator = WavetableOscillator::new_bandlimited(Waveforms::SQUARE_WAVE, 1.0, 128, 16);
+    /// assert_eq!(oscillator.get_wavetable().len(), 128);
+    /// ```
+    pub fn new_bandlimited(
+        wave_function: fn(f32) -> f32,
+        max_time: f32,
+        wavetable_size: usize,
+        max_harmonics: usize,
+    ) -> Self {
+        let analysis_size = wavetable_size.max(1) * 8;
+        let samples: Vec<f32> = (0..analysis_size)
+            .map(|i| {
+                let time_value = i as f32 / analysis_size as f32;
+                wave_function(max_time * time_value).clamp(-1.0, 1.0)
+            })
+            .collect();
+        let offset = samples.iter().sum::<f32>() / analysis_size as f32;
+        let num_harmonics = max_harmonics.min(wavetable_size / 2);
+        let mut harmonics: Vec<(f32, f32)> = Vec::new();
+        for harmonic in 1..=num_harmonics {
+            let mut cosine_sum = 0.0;
+            let mut sine_sum = 0.0;
+            for (i, sample) in samples.iter().enumerate() {
+                let phase =
+                    2.0 * std::f32::consts::PI * (harmonic * i) as f32 / analysis_size as f32;
+                cosine_sum += sample * phase.cos();
+                sine_sum += sample * phase.sin();
+            }
+            //Lanczos sigma factor to reduce the ringing caused by cutting off the harmonics
+            let sigma_phase = std::f32::consts::PI * harmonic as f32 / (num_harmonics + 1) as f32;
+            let sigma = sigma_phase.sin() / sigma_phase;
+            harmonics.push((
+                2.0 * sigma * cosine_sum / analysis_size as f32,
+                2.0 * sigma * sine_sum / analysis_size as f32,
+            ));
+        }
+        let mut wavetable: Vec<f32> = (0..wavetable_size)
+            .map(|i| {
+                let mut wave_value = offset;
+                for (index, (cosine, sine)) in harmonics.iter().enumerate() {
+                    let phase = 2.0 * std::f32::consts::PI * ((index + 1) * i) as f32
+                        / wavetable_size as f32;
+                    wave_value += cosine * phase.cos() + sine * phase.sin();
+                }
+                wave_value
+            })
+            .collect();
+        let peak = wavetable
+            .iter()
+            .fold(0.0, |peak: f32, value| peak.max(value.abs()));
+        if peak > 1.0 {
+            for value in &mut wavetable {
+                *value /= peak;
+            }
+        }
+        Self {
+            wavetable,
+            voices: Vec::new(),
+            volume: 0.2,
+            release_time: 0.01,
+            unison_voices: 1,
+            unison_detune: 0.0,
+            interpolation: Interpolation::Linear,
+            next_group: 0,
         }
     }
+
+    /// Returns a vector of [`f32`] with the values stored in the wavetable.
+    pub fn get_wavetable(&self) -> Vec<f32> {
+        self.wavetable.clone()
+    }
+
+    /// Sets the unison of the oscillator, so that each voice that is added plays several copies of
+    /// its frequency which are detuned and spread evenly between a given amount of cents below and
+    /// above the frequency. Removing, releasing or changing a voice affects all of its copies, even
+    /// if the unison is changed after the voice is added. By default each voice plays a single copy
+    /// of its frequency.
+    ///
+    /// # Parameters
+    ///
+    /// - `voices`: The amount of copies played for each voice, which must be at least 1.
+    /// - `detune_cents`: The maximum amount of cents that a copy is detuned by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::Synth;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    ///
+    /// let mut oscillator = WavetableOscillator::default();
+    /// oscillator.set_unison(3, 10.0);
+    /// oscillator.add_voice(440.0);
+    /// assert_eq!(oscillator.get_num_voices(), 3);
+    /// oscillator.remove_voice(440.0);
+    /// assert!(oscillator.is_silent());
+    /// ```
+    pub fn set_unison(&mut self, voices: usize, detune_cents: f32) {
+        self.unison_voices = voices.max(1);
+        self.unison_detune = detune_cents.abs();
+    }
+
+    /// Returns the amount of voices which are currently being played by the oscillator, including
+    /// each of the copies played for the unison and the voices which are fading out.
+    pub fn get_num_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Sets the time in seconds that it takes for a voice to fade out after it is released. The
+    /// default release time is 0.01 seconds.
+    ///
+    /// # Parameters
+    ///
+    /// - `release_time`: The length of the release in seconds, which cannot be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::Synth;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    ///
+    /// let mut oscillator = WavetableOscillator::default();
+    /// oscillator.set_release_time(0.05);
+    /// oscillator.add_voice(440.0);
+    /// oscillator.release_voice(440.0);
+    /// assert!(!oscillator.is_silent());
+    /// ```
+    pub fn set_release_time(&mut self, release_time: f32) {
+        self.release_time = release_time.max(0.0);
+    }
+
+    /// Sets how the oscillator reads the values between the points stored in the wavetable. The
+    /// default mode is [`Interpolation::Linear`].
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: An [`Interpolation`] representing how the values of the wavetable are read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::audio::common::Interpolation;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    ///
+    /// let mut oscillator = WavetableOscillator::default();
+    /// oscillator.set_interpolation(Interpolation::Cubic);
+    /// assert_eq!(oscillator.get_interpolation(), Interpolation::Cubic);
+    /// ```
+    pub fn set_interpolation(&mut self, mode: Interpolation) {
+        self.interpolation = mode;
+    }
+
+    /// Returns the [`Interpolation`] used to read the values of the wavetable.
+    pub fn get_interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    fn find_group(&self, frequency: f32) -> Option<usize> {
+        //Voices which are still playing are preferred over voices which are fading out
+        self.voices
+            .iter()
+            .find(|voice| !voice.is_released() && voice.get_frequency() == frequency)
+            .or_else(|| {
+                self.voices
+                    .iter()
+                    .find(|voice| voice.get_frequency() == frequency)
+            })
+            .map(|voice| voice.get_group())
+    }
 }
 
 impl Synth for WavetableOscillator {
@@ -112,16 +359,64 @@ impl Synth for WavetableOscillator {
     }
 
     fn add_voice(&mut self, frequency: f32) {
-        self.voices.push(WavetableVoice::new(frequency));
+        self.add_voice_with_amplitude(frequency, 1.0);
+    }
+
+    fn add_voice_with_amplitude(&mut self, frequency: f32, amplitude: f32) {
+        let group = self.next_group;
+        self.next_group = self.next_group.wrapping_add(1);
+        for index in 0..self.unison_voices {
+            let detune_cents = if self.unison_voices > 1 {
+                self.unison_detune * (2.0 * index as f32 / (self.unison_voices - 1) as f32 - 1.0)
+            } else {
+                0.0
+            };
+            let detune_ratio = 2.0_f32.powf(detune_cents / 1200.0);
+            self.voices.push(WavetableVoice::new(
+                group,
+                frequency,
+                detune_ratio,
+                amplitude.clamp(0.0, 1.0),
+            ));
+        }
     }
 
     fn remove_voice(&mut self, frequency: f32) {
-        if let Some(index) = self
+        if let Some(group) = self.find_group(frequency) {
+            self.voices.retain(|voice| voice.get_group() != group);
+        }
+    }
+
+    fn release_voice(&mut self, frequency: f32) {
+        if let Some(group) = self
             .voices
             .iter()
-            .position(|voice| voice.get_frequency() == frequency)
+            .find(|voice| !voice.is_released() && voice.get_frequency() == frequency)
+            .map(|voice| voice.get_group())
         {
-            self.voices.remove(index);
+            for voice in self
+                .voices
+                .iter_mut()
+                .filter(|voice| voice.get_group() == group)
+            {
+                voice.release();
+            }
+        }
+    }
+
+    fn is_silent(&self) -> bool {
+        self.voices.is_empty()
+    }
+
+    fn change_voice(&mut self, old_frequency: f32, new_frequency: f32) {
+        if let Some(group) = self.find_group(old_frequency) {
+            for voice in self
+                .voices
+                .iter_mut()
+                .filter(|voice| voice.get_group() == group)
+            {
+                voice.set_frequency(new_frequency);
+            }
         }
     }
 
@@ -135,8 +430,29 @@ impl Synth for WavetableOscillator {
             let lerp_frac = voice.get_table_index() - current_index as f32;
             let current_value = self.wavetable[current_index];
             let next_value = self.wavetable[next_index];
-            let lerp_value = current_value + lerp_frac * (next_value - current_value);
-            sample += lerp_value;
+            let table_value = match self.interpolation {
+                Interpolation::None => {
+                    if lerp_frac < 0.5 {
+                        current_value
+                    } else {
+                        next_value
+                    }
+                }
+                Interpolation::Linear => current_value + lerp_frac * (next_value - current_value),
+                Interpolation::Cubic => {
+                    let previous_value =
+                        self.wavetable[(current_index + table_size - 1) % table_size];
+                    let after_value = self.wavetable[(current_index + 2) % table_size];
+                    0.5 * (2.0 * current_value
+                        + (next_value - previous_value) * lerp_frac
+                        + (2.0 * previous_value - 5.0 * current_value + 4.0 * next_value
+                            - after_value)
+                            * lerp_frac.powi(2)
+                        + (3.0 * current_value - previous_value - 3.0 * next_value + after_value)
+                            * lerp_frac.powi(3))
+                }
+            };
+            sample += table_value * voice.get_amplitude() * voice.get_gain();
             active_voices += 1;
         }
         if active_voices == 0 {
@@ -150,7 +466,10 @@ impl Synth for WavetableOscillator {
         for voice in &mut self.voices {
             let table_size = self.wavetable.len();
             voice.add_delta_time(table_size, sample_rate);
+            voice.add_release_time(self.release_time, sample_rate);
         }
+        self.voices
+            .retain(|voice| !voice.is_released() || voice.get_gain() > 0.0);
     }
 }
 
@@ -166,6 +485,11 @@ impl From<&[f32]> for WavetableOscillator {
             wavetable: value.iter().map(|value| value.clamp(-1.0, 1.0)).collect(),
             voices: Vec::new(),
             volume: 0.2,
+            release_time: 0.01,
+            unison_voices: 1,
+            unison_detune: 0.0,
+            interpolation: Interpolation::Linear,
+            next_group: 0,
         }
     }
 }
@@ -1,9 +1,12 @@
-use crate::common::{InputError, TriadQuality};
+use crate::common::{
+    ArpeggioDirection, ChordFunction, DropVoicing, IncompleteChordError, InputError, TriadQuality,
+};
 use crate::interval::Interval;
 use crate::note::Note;
 use crate::pitchclass::PitchClass;
 use crate::scale::Scale;
 use regex::Regex;
+use std::str::FromStr;
 
 /// A structure which holds a chord, which is a group of consecutive intervals with a given
 /// inversion. A chord can optionally have a tonic which will define the pitch classes of each of
@@ -319,6 +322,138 @@ impl Chord {
         Ok(chord)
     }
 
+    /// Returns a vector with the correctly spelled name of each note of the chord defined by a
+    /// roman numeral in a given key. The letter of each note is taken from the scale degrees of the
+    /// key, so the notes are spelled consistently with the key signature, for example the IV chord
+    /// in F major is spelled B♭, D and F rather than A♯, D and F. If the numeral or the key are not
+    /// valid, or if a note cannot be spelled with at most two accidentals, an [`InputError`] is
+    /// returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `input_numeral`: A string representing the numeral of the chord, in the same format as
+    ///   [`Chord::from_numeral`].
+    /// - `key`: A string with the name of the tonic of the key, such as `"F"`, `"Bb"` or `"E♭"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    ///
+    /// let names = Chord::spell_numeral("IV", "F").unwrap();
+    /// assert_eq!(names, vec!["B♭", "D", "F"]);
+    /// ```
+    pub fn spell_numeral(input_numeral: &str, key: &str) -> Result<Vec<String>, InputError> {
+        let letters = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+        let natural_values = [0, 2, 4, 5, 7, 9, 11];
+        let invalid_key_error = InputError {
+            message: "the key must be a letter from A to G optionally followed by accidentals",
+        };
+        let key_tonic = PitchClass::from_str(key).map_err(|_| invalid_key_error)?;
+        let key_letter = key
+            .chars()
+            .next()
+            .and_then(|letter| letters.iter().position(|&x| x == letter))
+            .ok_or(invalid_key_error)?;
+        let chord = Self::from_numeral(input_numeral, key_tonic, None)?;
+        let numeral = input_numeral
+            .trim_start_matches(['b', '♭', '#', '♯'])
+            .chars()
+            .take_while(|character| matches!(character, 'I' | 'V' | 'i' | 'v'))
+            .collect::<String>()
+            .to_ascii_uppercase();
+        let degree = ["I", "II", "III", "IV", "V", "VI", "VII"]
+            .iter()
+            .position(|&x| x == numeral)
+            .unwrap();
+        let chord_tonic = chord.get_tonic().unwrap();
+        let mut names = Vec::new();
+        for (index, interval) in chord.get_intervals().iter().enumerate() {
+            let letter = (key_letter + degree + 2 * index) % 7;
+            let value = chord_tonic.get_offset((interval.get_value() % 12) as i8);
+            let difference = (value.get_value() as i8 - natural_values[letter] + 18) % 12 - 6;
+            let accidental = match difference {
+                -2 => "♭♭",
+                -1 => "♭",
+                0 => "",
+                1 => "♯",
+                2 => "♯♯",
+                _ => {
+                    return Err(InputError {
+                        message: "the notes of the chord cannot be spelled in the given key",
+                    })
+                }
+            };
+            names.push(format!("{}{}", letters[letter], accidental));
+        }
+        Ok(names)
+    }
+
+    /// Returns a [`Result`] which can contain a boolean which is true if any two of the given note
+    /// names of a chord represent the same pitch class with different letters, such as G♯ and A♭,
+    /// or false if otherwise. An [`InputError`] is returned if any of the names is not a valid
+    /// pitch class.
+    ///
+    /// # Parameters
+    ///
+    /// - `names`: A slice with the spelled names of the notes of the chord, starting with the root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    ///
+    /// assert!(Chord::has_enharmonic_conflict(&["E", "G#", "B", "Ab"]).unwrap());
+    /// assert!(!Chord::has_enharmonic_conflict(&["E", "G#", "B"]).unwrap());
+    /// ```
+    pub fn has_enharmonic_conflict(names: &[&str]) -> Result<bool, InputError> {
+        let pitch_classes = names
+            .iter()
+            .map(|name| PitchClass::from_str(name))
+            .collect::<Result<Vec<PitchClass>, InputError>>()?;
+        let letters: Vec<Option<char>> = names.iter().map(|name| name.chars().next()).collect();
+        for (index, pitch_class) in pitch_classes.iter().enumerate() {
+            for (other_index, other_pitch_class) in pitch_classes.iter().enumerate().skip(index + 1)
+            {
+                if pitch_class == other_pitch_class && letters[index] != letters[other_index] {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns a [`Result`] which can contain the given note names of a chord respelled so that
+    /// every note with the same pitch class is spelled in the same way, or an [`InputError`] if any
+    /// of the names is not a valid pitch class. Each note takes the spelling of the first name with
+    /// the same pitch class, so the root always keeps its spelling.
+    ///
+    /// # Parameters
+    ///
+    /// - `names`: A slice with the spelled names of the notes of the chord, starting with the root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    ///
+    /// let respelled = Chord::respell_consistently(&["Ab", "C", "Eb", "G#"]).unwrap();
+    /// assert_eq!(respelled, vec!["Ab", "C", "Eb", "Ab"]);
+    /// ```
+    pub fn respell_consistently(names: &[&str]) -> Result<Vec<String>, InputError> {
+        let pitch_classes = names
+            .iter()
+            .map(|name| PitchClass::from_str(name))
+            .collect::<Result<Vec<PitchClass>, InputError>>()?;
+        Ok(pitch_classes
+            .iter()
+            .map(|pitch_class| {
+                let first_index = pitch_classes.iter().position(|x| x == pitch_class).unwrap();
+                names[first_index].to_string()
+            })
+            .collect())
+    }
+
     /// Adds an interval on top of the current chord.
     ///
     /// # Parameters
@@ -371,6 +506,26 @@ impl Chord {
         values.iter().map(|value| Interval::from(*value)).collect()
     }
 
+    /// Returns a vector with the number of semitones of each interval of the current chord in root
+    /// position, sorted in ascending order and ignoring the inversion of the chord.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    ///
+    /// let mut chord = Chord::from_triad(TriadQuality::Major, None, None);
+    /// chord.set_inversion(2);
+    /// assert_eq!(chord.root_semitones(), vec![0, 4, 7]);
+    /// ```
+    pub fn root_semitones(&self) -> Vec<usize> {
+        self.intervals
+            .iter()
+            .map(|interval| interval.get_value() as usize)
+            .collect()
+    }
+
     /// Sets the inversion of the current chord which changes the order of the intervals in the
     /// chord.
     ///
@@ -414,6 +569,42 @@ impl Chord {
         self.inversion as u8
     }
 
+    /// Returns a [`Result`] which can contain a copy of the current chord with the inversion that
+    /// places a given pitch class in the bass, or an [`InputError`] if the chord does not have a
+    /// tonic or does not contain the pitch class. This can be used to build slash chords.
+    ///
+    /// # Parameters
+    ///
+    /// - `bass`: A reference to the [`PitchClass`] that will be the lowest note of the chord.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::F), None);
+    /// let slash_chord = chord.with_bass(&PitchClass::C).unwrap();
+    /// assert_eq!(slash_chord.get_inversion(), 2);
+    /// assert!(chord.with_bass(&PitchClass::D).is_err());
+    /// ```
+    pub fn with_bass(&self, bass: &PitchClass) -> Result<Chord, InputError> {
+        let tonic = self.tonic.ok_or(InputError {
+            message: "the chord does not have a tonic",
+        })?;
+        let inversion = self
+            .intervals
+            .iter()
+            .position(|interval| tonic.get_offset((interval.get_value() % 12) as i8) == *bass)
+            .ok_or(InputError {
+                message: "the chord does not contain the bass pitch class",
+            })?;
+        let mut chord = self.clone();
+        chord.inversion = inversion;
+        Ok(chord)
+    }
+
     /// Sets the tonic of the current chord to the [`Option<PitchClass>`] passed to this function.
     /// If this is [`None`], it will unset the current pitch class of the chord.
     ///
@@ -446,6 +637,568 @@ impl Chord {
     pub fn get_octave(&self) -> Option<i8> {
         self.octave
     }
+
+    /// Returns an [`f32`] representing how dissonant the chord is, which is calculated from the
+    /// interval between every pair of notes in the chord. Intervals such as minor seconds, major
+    /// sevenths and tritones increase the score, while intervals such as thirds, sixths, fourths
+    /// and fifths decrease it, so chords with a lower score are more consonant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::interval::Interval;
+    ///
+    /// let major = Chord::from_triad(TriadQuality::Major, None, None);
+    /// let mut dominant_seventh = major.clone();
+    /// dominant_seventh.add_interval(Interval::MINOR_SEVENTH);
+    /// assert!(major.dissonance() < dominant_seventh.dissonance());
+    /// ```
+    pub fn dissonance(&self) -> f32 {
+        const INTERVAL_CLASS_ROUGHNESS: [f32; 7] = [-0.5, 1.0, 0.4, -0.3, -0.3, -0.5, 0.8];
+        let values: Vec<u64> = self
+            .intervals
+            .iter()
+            .map(|interval| interval.get_value())
+            .collect();
+        let mut score = 0.0;
+        for (index, lower) in values.iter().enumerate() {
+            for upper in &values[index + 1..] {
+                let interval_class = ((upper - lower) % 12) as usize;
+                score += INTERVAL_CLASS_ROUGHNESS[interval_class.min(12 - interval_class)];
+            }
+        }
+        score
+    }
+
+    /// Returns a [`Result`] which can contain an [`Option<Note>`] with the note of the chord that
+    /// has a specific function, such as the third or the seventh, or [`None`] if the chord does not
+    /// contain a note with that function. The note takes the inversion of the chord into account.
+    /// If the chord does not have a tonic or an octave then an [`IncompleteChordError`] is
+    /// returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A [`ChordFunction`] representing the function of the note to return.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::{ChordFunction, TriadQuality};
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use std::str::FromStr;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    /// let fifth = chord.chord_tone(ChordFunction::Fifth).unwrap();
+    /// assert_eq!(fifth, Some(Note::from_str("E4").unwrap()));
+    /// ```
+    pub fn chord_tone(
+        &self,
+        function: ChordFunction,
+    ) -> Result<Option<Note>, IncompleteChordError> {
+        let notes = Vec::<Note>::try_from(self.clone())?;
+        let values = function.get_interval_values();
+        let position = self
+            .intervals
+            .iter()
+            .position(|interval| values.contains(&interval.get_value()));
+        Ok(position.map(|index| {
+            notes[(index + self.intervals.len() - self.inversion) % self.intervals.len()]
+        }))
+    }
+
+    /// Transforms the current chord into a drop voicing, lowering some of its notes by an octave
+    /// counting from the top note of the chord with its inversion applied. The lowest note of the
+    /// resulting voicing becomes the new tonic of the chord, the octave of the chord is updated
+    /// accordingly and the inversion is reset. If the chord does not have enough notes for the
+    /// voicing an [`InputError`] is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `which`: A [`DropVoicing`] representing the drop voicing to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::{DropVoicing, TriadQuality};
+    /// use music_tools::interval::Interval;
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use std::str::FromStr;
+    ///
+    /// let mut chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// chord.add_interval(Interval::MAJOR_SEVENTH);
+    /// chord.drop(DropVoicing::Drop2).unwrap();
+    /// let notes: Vec<Note> = Vec::try_from(chord).unwrap();
+    /// let expected: Vec<Note> = ["G3", "C4", "E4", "B4"]
+    ///     .iter()
+    ///     .map(|name| Note::from_str(name).unwrap())
+    ///     .collect();
+    /// assert_eq!(notes, expected);
+    /// ```
+    pub fn drop(&mut self, which: DropVoicing) -> Result<(), InputError> {
+        let mut values: Vec<i64> = self
+            .get_intervals()
+            .iter()
+            .map(|interval| interval.get_value() as i64)
+            .collect();
+        for position in which.get_positions() {
+            if *position > values.len() {
+                return Err(InputError {
+                    message: "the chord does not have enough notes for the drop voicing",
+                });
+            }
+            let index = values.len() - position;
+            values[index] -= 12;
+        }
+        values.sort();
+        let lowest = values[0];
+        if let Some(tonic) = self.tonic {
+            let bass_value = tonic.get_value() as i64 + lowest;
+            self.tonic = Some(tonic.get_offset(lowest.rem_euclid(12) as i8));
+            self.octave = self
+                .octave
+                .map(|octave| octave + bass_value.div_euclid(12) as i8);
+        }
+        self.intervals = values
+            .iter()
+            .map(|value| Interval::from((value - lowest) as u64))
+            .collect();
+        self.inversion = 0;
+        Ok(())
+    }
+
+    /// Returns the prime form of the pitch class set of the current chord, which is the most
+    /// compact arrangement of its pitch classes, or of the inversion of its pitch classes,
+    /// transposed so that it starts at 0. The pitch classes are compared first by the span between
+    /// the first and last pitch class, and then by how tightly they are packed to the left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Major, None, None);
+    /// assert_eq!(chord.prime_form(), vec![0, 3, 7]);
+    /// ```
+    pub fn prime_form(&self) -> Vec<usize> {
+        let mut pitch_classes: Vec<usize> = self
+            .intervals
+            .iter()
+            .map(|interval| (interval.get_value() % 12) as usize)
+            .collect();
+        pitch_classes.sort();
+        pitch_classes.dedup();
+        let mut inverted: Vec<usize> = pitch_classes
+            .iter()
+            .map(|value| (12 - value) % 12)
+            .collect();
+        inverted.sort();
+        [pitch_classes, inverted]
+            .iter()
+            .flat_map(|set| {
+                (0..set.len()).map(move |rotation| {
+                    let start = set[rotation];
+                    (0..set.len())
+                        .map(|index| (set[(rotation + index) % set.len()] + 12 - start) % 12)
+                        .collect::<Vec<usize>>()
+                })
+            })
+            .min_by_key(|candidate| (candidate[candidate.len() - 1], candidate.clone()))
+            .unwrap()
+    }
+
+    /// Returns an [`Option<String>`] which can contain the Forte number of the pitch class set of
+    /// the current chord, such as 3-11 for major and minor triads. The Forte number is looked up
+    /// from the prime form of the chord, and [`None`] is returned if the chord does not contain
+    /// between 3 and 6 distinct pitch classes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::interval::Interval;
+    ///
+    /// let major = Chord::from_triad(TriadQuality::Major, None, None);
+    /// assert_eq!(major.forte_number(), Some(String::from("3-11")));
+    /// let mut diminished = Chord::from_triad(TriadQuality::Diminished, None, None);
+    /// diminished.add_interval(Interval::MAJOR_SIXTH);
+    /// assert_eq!(diminished.forte_number(), Some(String::from("4-28")));
+    /// ```
+    pub fn forte_number(&self) -> Option<String> {
+        let prime_form = self.prime_form();
+        FORTE_NUMBERS
+            .iter()
+            .find(|(set, _)| *set == prime_form.as_slice())
+            .map(|(_, name)| name.to_string())
+    }
+
+    /// Returns an iterator over every inversion of the current chord, starting from the root
+    /// position and ending with the inversion where the highest interval of the chord is in the
+    /// bass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// for inversion in chord.inversions() {
+    ///     let notes: Vec<Note> = Vec::try_from(inversion).unwrap();
+    ///     assert_eq!(notes.len(), 3);
+    /// }
+    /// ```
+    pub fn inversions(&self) -> impl Iterator<Item = Chord> + '_ {
+        (0..self.intervals.len()).map(|inversion| {
+            let mut chord = self.clone();
+            chord.inversion = inversion;
+            chord
+        })
+    }
+
+    /// Returns true if the current chord and another chord contain the same set of pitch classes,
+    /// regardless of their octave, inversion or doubled notes. Chords without a tonic are compared
+    /// by the pitch classes of their intervals above the root, and are never equal to chords with a
+    /// tonic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A reference to the [`Chord`] to compare the current chord with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(3));
+    /// let mut inverted = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(5));
+    /// inverted.set_inversion(1);
+    /// assert!(c_major.same_pitch_classes(&inverted));
+    /// assert_ne!(c_major, inverted);
+    /// ```
+    pub fn same_pitch_classes(&self, other: &Chord) -> bool {
+        let pitch_class_set = |chord: &Chord| {
+            let offset = chord.tonic.map_or(0, |tonic| tonic.get_value() as u64);
+            let mut values: Vec<u64> = chord
+                .intervals
+                .iter()
+                .map(|interval| (interval.get_value() + offset) % 12)
+                .collect();
+            values.sort();
+            values.dedup();
+            values
+        };
+        self.tonic.is_some() == other.tonic.is_some()
+            && pitch_class_set(self) == pitch_class_set(other)
+    }
+
+    /// Returns a [`Result`] which can contain a vector of the pitch classes that are present in
+    /// both the current chord and another chord, in the order they appear in the current chord.
+    /// If either of the chords does not have a tonic then an [`IncompleteChordError`] is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A reference to the [`Chord`] to compare the current chord with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), None);
+    /// let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), None);
+    /// assert_eq!(
+    ///     c_major.common_tones(&a_minor).unwrap(),
+    ///     vec![PitchClass::C, PitchClass::E]
+    /// );
+    /// ```
+    pub fn common_tones(&self, other: &Chord) -> Result<Vec<PitchClass>, IncompleteChordError> {
+        let pitch_classes = Vec::<PitchClass>::try_from(self.clone())?;
+        let other_pitch_classes = Vec::<PitchClass>::try_from(other.clone())?;
+        let mut common: Vec<PitchClass> = Vec::new();
+        for pitch_class in pitch_classes {
+            if other_pitch_classes.contains(&pitch_class) && !common.contains(&pitch_class) {
+                common.push(pitch_class);
+            }
+        }
+        Ok(common)
+    }
+
+    /// Returns a [`Result`] which can contain a vector of the notes that are present in both the
+    /// current chord and another chord, comparing both the pitch class and the octave of each
+    /// note. If either of the chords does not have a tonic or an octave then an
+    /// [`IncompleteChordError`] is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A reference to the [`Chord`] to compare the current chord with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use std::str::FromStr;
+    ///
+    /// let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    /// assert_eq!(
+    ///     c_major.common_notes(&a_minor).unwrap(),
+    ///     vec![Note::from_str("C4").unwrap(), Note::from_str("E4").unwrap()]
+    /// );
+    /// ```
+    pub fn common_notes(&self, other: &Chord) -> Result<Vec<Note>, IncompleteChordError> {
+        let notes = Vec::<Note>::try_from(self.clone())?;
+        let other_notes = Vec::<Note>::try_from(other.clone())?;
+        let mut common: Vec<Note> = Vec::new();
+        for note in notes {
+            if other_notes.contains(&note) && !common.contains(&note) {
+                common.push(note);
+            }
+        }
+        Ok(common)
+    }
+
+    /// Returns a [`Result`] which can contain the sequence of notes of an arpeggio of the chord,
+    /// or an [`IncompleteChordError`] if the chord does not have a tonic or an octave. The notes
+    /// are chosen in the same way as they are when the chord is arpeggiated by the audio player.
+    ///
+    /// # Parameters
+    ///
+    /// - `direction`: An [`ArpeggioDirection`] enum representing the direction that the chord will
+    ///   be arpeggiated in.
+    /// - `total_notes`: The total amount of notes in the arpeggio.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::{ArpeggioDirection, TriadQuality};
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use std::str::FromStr;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// let arpeggio = chord.arpeggiate(ArpeggioDirection::Down, 4).unwrap();
+    /// let expected = ["G4", "E4", "C4", "G4"].map(|name| Note::from_str(name).unwrap());
+    /// assert_eq!(arpeggio, expected);
+    /// ```
+    pub fn arpeggiate(
+        &self,
+        direction: ArpeggioDirection,
+        total_notes: usize,
+    ) -> Result<Vec<Note>, IncompleteChordError> {
+        let notes = Vec::<Note>::try_from(self.clone())?;
+        Ok(direction
+            .get_indices(notes.len(), total_notes)
+            .into_iter()
+            .map(|index| notes[index])
+            .collect())
+    }
+
+    /// Returns a [`Result`] which can contain a vector of chords that can be used to substitute the
+    /// current chord, or an [`IncompleteChordError`] if the chord does not have a tonic. A dominant
+    /// seventh chord is substituted by its tritone substitution and by the minor seventh chord
+    /// whose root is a perfect fifth above its root, which forms the ii of a ii-V progression. A
+    /// major triad is substituted by its relative minor triad and a minor triad is substituted by
+    /// its relative major triad. Any other chord has no substitutions. If the chord has an octave
+    /// then the root of each substitution is placed within a tritone of the root of the chord.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let g7 = Chord::from_numeral("V7", PitchClass::C, None).unwrap();
+    /// let d_flat7 = Chord::from_numeral("bII7", PitchClass::C, None).unwrap();
+    /// assert!(g7.substitutions().unwrap().contains(&d_flat7));
+    /// ```
+    pub fn substitutions(&self) -> Result<Vec<Chord>, IncompleteChordError> {
+        let tonic = self.tonic.ok_or(IncompleteChordError {
+            needs_tonic: true,
+            needs_octave: false,
+            has_tonic: false,
+            has_octave: self.octave.is_some(),
+        })?;
+        let values: Vec<u64> = self
+            .intervals
+            .iter()
+            .map(|interval| interval.get_value())
+            .collect();
+        let substitution = |offset: isize, quality: TriadQuality, seventh: Option<Interval>| {
+            let root = Note::new(tonic, self.octave.unwrap_or(4)).at_offset(offset);
+            let octave = self.octave.map(|_| root.get_octave());
+            let mut chord = Chord::from_triad(quality, Some(root.get_pitch_class()), octave);
+            if let Some(interval) = seventh {
+                chord.add_interval(interval);
+            }
+            chord
+        };
+        let substitutions = match values.as_slice() {
+            [0, 4, 7, 10] => vec![
+                substitution(6, TriadQuality::Major, Some(Interval::MINOR_SEVENTH)),
+                substitution(-5, TriadQuality::Minor, Some(Interval::MINOR_SEVENTH)),
+            ],
+            [0, 4, 7] => vec![substitution(-3, TriadQuality::Minor, None)],
+            [0, 3, 7] => vec![substitution(3, TriadQuality::Major, None)],
+            _ => Vec::new(),
+        };
+        Ok(substitutions)
+    }
+
+    /// Revoices the current chord so that every note lies within an inclusive range of notes, by
+    /// moving each note of the chord to the lowest octave in which it fits within the range. Notes
+    /// that end up being the same after being moved are merged. The lowest note of the resulting
+    /// voicing becomes the new tonic of the chord, the octave of the chord is updated accordingly
+    /// and the inversion is reset. An [`InputError`] is returned if the chord does not have a
+    /// tonic or an octave, or if any of its pitch classes cannot be placed within the range.
+    ///
+    /// # Parameters
+    ///
+    /// - `low`: The lowest [`Note`] of the range.
+    /// - `high`: The highest [`Note`] of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use std::str::FromStr;
+    ///
+    /// let mut chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// chord
+    ///     .constrain_to_range(Note::from_str("E3").unwrap(), Note::from_str("D4").unwrap())
+    ///     .unwrap();
+    /// let notes: Vec<Note> = Vec::try_from(chord).unwrap();
+    /// let expected: Vec<Note> = ["E3", "G3", "C4"]
+    ///     .iter()
+    ///     .map(|name| Note::from_str(name).unwrap())
+    ///     .collect();
+    /// assert_eq!(notes, expected);
+    /// ```
+    pub fn constrain_to_range(&mut self, low: Note, high: Note) -> Result<(), InputError> {
+        let (tonic, octave) = match (self.tonic, self.octave) {
+            (Some(tonic), Some(octave)) => (tonic, octave),
+            _ => {
+                return Err(InputError {
+                    message: "the chord must have a tonic and an octave to be constrained",
+                })
+            }
+        };
+        let root_value = octave as i64 * 12 + tonic.get_value() as i64;
+        let low_value = low.get_value() as i64;
+        let high_value = high.get_value() as i64;
+        let mut values: Vec<i64> = Vec::new();
+        for interval in self.get_intervals() {
+            let value = root_value + interval.get_value() as i64;
+            let constrained = low_value + (value - low_value).rem_euclid(12);
+            if constrained > high_value {
+                return Err(InputError {
+                    message: "the chord cannot be voiced within the given range",
+                });
+            }
+            values.push(constrained);
+        }
+        values.sort();
+        values.dedup();
+        let lowest = values[0];
+        self.tonic = Some(tonic.get_offset((lowest - root_value).rem_euclid(12) as i8));
+        self.octave = Some(lowest.div_euclid(12) as i8);
+        self.intervals = values
+            .iter()
+            .map(|value| Interval::from((value - lowest) as u64))
+            .collect();
+        self.inversion = 0;
+        Ok(())
+    }
+
+    /// Returns a [`Result`] which can contain a vector of the MIDI indices of the notes of the
+    /// current chord, from the lowest to the highest note, or an [`InputError`] if the chord does
+    /// not have a tonic or an octave or if any of its notes lies outside of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// assert_eq!(chord.to_midi_indices().unwrap(), vec![60, 64, 67]);
+    /// ```
+    pub fn to_midi_indices(&self) -> Result<Vec<u8>, InputError> {
+        let notes = Vec::<Note>::try_from(self.clone()).map_err(|_| InputError {
+            message: "the chord must have a tonic and an octave to be converted to midi indices",
+        })?;
+        notes
+            .iter()
+            .map(|note| {
+                note.get_midi_index().ok_or(InputError {
+                    message: "the notes of the chord must be within the midi range",
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the negative harmony of the current chord, which is obtained by mirroring each
+    /// pitch class of the chord around the axis that lies halfway between the tonic of a key and
+    /// its fifth, such as the axis between E♭ and E in the key of C. The highest note of the chord
+    /// in root position becomes the root of the mirrored chord, which is returned in root position
+    /// with the same octave as the current chord. If the current chord does not have a tonic, the
+    /// mirrored chord does not have a tonic either.
+    ///
+    /// # Parameters
+    ///
+    /// - `axis_tonic`: A reference to the [`PitchClass`] representing the tonic of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::TriadQuality;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    /// let c_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::C), Some(4));
+    /// assert_eq!(c_major.negative(&PitchClass::C), c_minor);
+    /// ```
+    pub fn negative(&self, axis_tonic: &PitchClass) -> Chord {
+        let semitones = self.root_semitones();
+        let highest = semitones[semitones.len() - 1];
+        let tonic = self.tonic.map(|tonic| {
+            let root = (tonic.get_value() as i8 - axis_tonic.get_value() as i8).rem_euclid(12);
+            axis_tonic.get_offset((7 - root - (highest % 12) as i8).rem_euclid(12))
+        });
+        let intervals = semitones
+            .iter()
+            .rev()
+            .map(|semitone| Interval::from((highest - semitone) as u64))
+            .collect();
+        Chord {
+            intervals,
+            tonic,
+            octave: self.octave,
+            inversion: 0,
+        }
+    }
 }
 
 impl Default for Chord {
@@ -515,8 +1268,8 @@ impl From<&[Note]> for Chord {
                 .map(|note| Interval::between_notes(smallest, *note))
                 .collect()
         };
-        let tonic = value.get(0).map(|note| note.get_pitch_class());
-        let octave = value.get(0).map(|note| note.get_octave());
+        let tonic = value.first().map(|note| note.get_pitch_class());
+        let octave = value.first().map(|note| note.get_octave());
         Chord {
             intervals,
             tonic,
@@ -554,7 +1307,7 @@ impl From<&[PitchClass]> for Chord {
         intervals.insert(0, Interval::PERFECT_UNISON);
         Chord {
             intervals,
-            tonic: value.get(0).copied(),
+            tonic: value.first().copied(),
             octave: None,
             inversion: 0,
         }
@@ -571,3 +1324,229 @@ impl From<Scale> for Chord {
         }
     }
 }
+
+/// Returns the chords that are commonly borrowed by a major key from its parallel natural minor
+/// key through modal interchange, which are the chords built on the numerals ii°, ♭III, iv, v, ♭VI
+/// and ♭VII.
+///
+/// # Parameters
+///
+/// - `tonic`: A reference to the [`PitchClass`] representing the tonic of the major key.
+/// - `octave`: The octave of the tonic of the major key.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::chord::{borrowed_chords, Chord};
+/// use music_tools::common::TriadQuality;
+/// use music_tools::pitchclass::PitchClass;
+///
+/// let chords = borrowed_chords(&PitchClass::C, 4);
+/// let f_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::F), Some(4));
+/// assert!(chords.contains(&f_minor));
+/// ```
+pub fn borrowed_chords(tonic: &PitchClass, octave: i8) -> Vec<Chord> {
+    ["ii°", "bIII", "iv", "v", "bVI", "bVII"]
+        .iter()
+        .map(|numeral| Chord::from_numeral(numeral, *tonic, Some(octave)).unwrap())
+        .collect()
+}
+
+/// Returns the negative harmony of a chord progression, which is obtained by replacing each chord
+/// with its mirror around the axis of a key as described in [`Chord::negative`].
+///
+/// # Parameters
+///
+/// - `chords`: A slice of [`Chord`] containing the progression.
+/// - `axis_tonic`: A reference to the [`PitchClass`] representing the tonic of the key.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::chord::{negative_progression, Chord};
+/// use music_tools::pitchclass::PitchClass;
+///
+/// let progression = ["IV", "V", "I"]
+///     .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap());
+/// let expected = ["v", "iv", "i"]
+///     .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap());
+/// assert_eq!(negative_progression(&progression, &PitchClass::C), expected);
+/// ```
+pub fn negative_progression(chords: &[Chord], axis_tonic: &PitchClass) -> Vec<Chord> {
+    chords
+        .iter()
+        .map(|chord| chord.negative(axis_tonic))
+        .collect()
+}
+
+/// The Forte numbers of every set class with between 3 and 6 pitch classes, indexed by the prime
+/// form computed by [`Chord::prime_form`].
+const FORTE_NUMBERS: [(&[usize], &str); 129] = [
+    (&[0, 1, 2], "3-1"),
+    (&[0, 1, 3], "3-2"),
+    (&[0, 1, 4], "3-3"),
+    (&[0, 1, 5], "3-4"),
+    (&[0, 1, 6], "3-5"),
+    (&[0, 2, 4], "3-6"),
+    (&[0, 2, 5], "3-7"),
+    (&[0, 2, 6], "3-8"),
+    (&[0, 2, 7], "3-9"),
+    (&[0, 3, 6], "3-10"),
+    (&[0, 3, 7], "3-11"),
+    (&[0, 4, 8], "3-12"),
+    (&[0, 1, 2, 3], "4-1"),
+    (&[0, 1, 2, 4], "4-2"),
+    (&[0, 1, 2, 5], "4-4"),
+    (&[0, 1, 2, 6], "4-5"),
+    (&[0, 1, 2, 7], "4-6"),
+    (&[0, 1, 3, 4], "4-3"),
+    (&[0, 1, 3, 5], "4-11"),
+    (&[0, 1, 3, 6], "4-13"),
+    (&[0, 1, 3, 7], "4-Z29"),
+    (&[0, 1, 4, 5], "4-7"),
+    (&[0, 1, 4, 6], "4-Z15"),
+    (&[0, 1, 4, 7], "4-18"),
+    (&[0, 1, 4, 8], "4-19"),
+    (&[0, 1, 5, 6], "4-8"),
+    (&[0, 1, 5, 7], "4-16"),
+    (&[0, 1, 5, 8], "4-20"),
+    (&[0, 1, 6, 7], "4-9"),
+    (&[0, 2, 3, 5], "4-10"),
+    (&[0, 2, 3, 6], "4-12"),
+    (&[0, 2, 3, 7], "4-14"),
+    (&[0, 2, 4, 6], "4-21"),
+    (&[0, 2, 4, 7], "4-22"),
+    (&[0, 2, 4, 8], "4-24"),
+    (&[0, 2, 5, 7], "4-23"),
+    (&[0, 2, 5, 8], "4-27"),
+    (&[0, 2, 6, 8], "4-25"),
+    (&[0, 3, 4, 7], "4-17"),
+    (&[0, 3, 5, 8], "4-26"),
+    (&[0, 3, 6, 9], "4-28"),
+    (&[0, 1, 2, 3, 4], "5-1"),
+    (&[0, 1, 2, 3, 5], "5-2"),
+    (&[0, 1, 2, 3, 6], "5-4"),
+    (&[0, 1, 2, 3, 7], "5-5"),
+    (&[0, 1, 2, 4, 5], "5-3"),
+    (&[0, 1, 2, 4, 6], "5-9"),
+    (&[0, 1, 2, 4, 7], "5-Z36"),
+    (&[0, 1, 2, 4, 8], "5-13"),
+    (&[0, 1, 2, 5, 6], "5-6"),
+    (&[0, 1, 2, 5, 7], "5-14"),
+    (&[0, 1, 2, 5, 8], "5-Z38"),
+    (&[0, 1, 2, 6, 7], "5-7"),
+    (&[0, 1, 2, 6, 8], "5-15"),
+    (&[0, 1, 3, 4, 6], "5-10"),
+    (&[0, 1, 3, 4, 7], "5-16"),
+    (&[0, 1, 3, 4, 8], "5-Z17"),
+    (&[0, 1, 3, 5, 6], "5-Z12"),
+    (&[0, 1, 3, 5, 7], "5-24"),
+    (&[0, 1, 3, 5, 8], "5-27"),
+    (&[0, 1, 3, 6, 7], "5-19"),
+    (&[0, 1, 3, 6, 8], "5-29"),
+    (&[0, 1, 3, 6, 9], "5-31"),
+    (&[0, 1, 3, 7, 8], "5-20"),
+    (&[0, 1, 4, 5, 7], "5-Z18"),
+    (&[0, 1, 4, 5, 8], "5-21"),
+    (&[0, 1, 4, 6, 8], "5-30"),
+    (&[0, 1, 4, 6, 9], "5-32"),
+    (&[0, 1, 4, 7, 8], "5-22"),
+    (&[0, 2, 3, 4, 6], "5-8"),
+    (&[0, 2, 3, 4, 7], "5-11"),
+    (&[0, 2, 3, 5, 7], "5-23"),
+    (&[0, 2, 3, 5, 8], "5-25"),
+    (&[0, 2, 3, 6, 8], "5-28"),
+    (&[0, 2, 4, 5, 8], "5-26"),
+    (&[0, 2, 4, 6, 8], "5-33"),
+    (&[0, 2, 4, 6, 9], "5-34"),
+    (&[0, 2, 4, 7, 9], "5-35"),
+    (&[0, 3, 4, 5, 8], "5-Z37"),
+    (&[0, 1, 2, 3, 4, 5], "6-1"),
+    (&[0, 1, 2, 3, 4, 6], "6-2"),
+    (&[0, 1, 2, 3, 4, 7], "6-Z36"),
+    (&[0, 1, 2, 3, 4, 8], "6-Z37"),
+    (&[0, 1, 2, 3, 5, 6], "6-Z3"),
+    (&[0, 1, 2, 3, 5, 7], "6-9"),
+    (&[0, 1, 2, 3, 5, 8], "6-Z40"),
+    (&[0, 1, 2, 3, 6, 7], "6-5"),
+    (&[0, 1, 2, 3, 6, 8], "6-Z41"),
+    (&[0, 1, 2, 3, 6, 9], "6-Z42"),
+    (&[0, 1, 2, 3, 7, 8], "6-Z38"),
+    (&[0, 1, 2, 4, 5, 6], "6-Z4"),
+    (&[0, 1, 2, 4, 5, 7], "6-Z11"),
+    (&[0, 1, 2, 4, 5, 8], "6-15"),
+    (&[0, 1, 2, 4, 6, 7], "6-Z12"),
+    (&[0, 1, 2, 4, 6, 8], "6-22"),
+    (&[0, 1, 2, 4, 6, 9], "6-Z46"),
+    (&[0, 1, 2, 4, 7, 8], "6-Z17"),
+    (&[0, 1, 2, 4, 7, 9], "6-Z47"),
+    (&[0, 1, 2, 5, 6, 7], "6-Z6"),
+    (&[0, 1, 2, 5, 6, 8], "6-Z43"),
+    (&[0, 1, 2, 5, 6, 9], "6-Z44"),
+    (&[0, 1, 2, 5, 7, 8], "6-18"),
+    (&[0, 1, 2, 5, 7, 9], "6-Z48"),
+    (&[0, 1, 2, 6, 7, 8], "6-7"),
+    (&[0, 1, 3, 4, 5, 7], "6-Z10"),
+    (&[0, 1, 3, 4, 5, 8], "6-14"),
+    (&[0, 1, 3, 4, 6, 7], "6-Z13"),
+    (&[0, 1, 3, 4, 6, 8], "6-Z24"),
+    (&[0, 1, 3, 4, 6, 9], "6-27"),
+    (&[0, 1, 3, 4, 7, 8], "6-Z19"),
+    (&[0, 1, 3, 4, 7, 9], "6-Z49"),
+    (&[0, 1, 3, 5, 6, 8], "6-Z25"),
+    (&[0, 1, 3, 5, 6, 9], "6-Z28"),
+    (&[0, 1, 3, 5, 7, 8], "6-Z26"),
+    (&[0, 1, 3, 5, 7, 9], "6-34"),
+    (&[0, 1, 3, 5, 8, 9], "6-31"),
+    (&[0, 1, 3, 6, 7, 9], "6-30"),
+    (&[0, 1, 3, 6, 8, 9], "6-Z29"),
+    (&[0, 1, 4, 5, 6, 8], "6-16"),
+    (&[0, 1, 4, 5, 8, 9], "6-20"),
+    (&[0, 1, 4, 6, 7, 9], "6-Z50"),
+    (&[0, 2, 3, 4, 5, 7], "6-8"),
+    (&[0, 2, 3, 4, 5, 8], "6-Z39"),
+    (&[0, 2, 3, 4, 6, 8], "6-21"),
+    (&[0, 2, 3, 4, 6, 9], "6-Z45"),
+    (&[0, 2, 3, 5, 6, 8], "6-Z23"),
+    (&[0, 2, 3, 5, 7, 9], "6-33"),
+    (&[0, 2, 4, 5, 7, 9], "6-32"),
+    (&[0, 2, 4, 6, 8, 10], "6-35"),
+];
+
+/// Returns the [`TriadQuality`] of a triad given the semitones of its notes, or [`None`] if the
+/// semitones do not form one of the triads that can be generated by [`Chord::from_triad`]. The
+/// first semitone is taken as the root of the triad, and the semitones are reduced to a single
+/// octave above the root before they are compared, so their order and octave do not matter.
+///
+/// # Parameters
+///
+/// - `semitones`: A slice with the semitones of each note of the triad.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::chord::classify_triad;
+/// use music_tools::common::TriadQuality;
+///
+/// assert_eq!(classify_triad(&[0, 3, 7]), Some(TriadQuality::Minor));
+/// assert_eq!(classify_triad(&[2, 14, 9, 5]), Some(TriadQuality::Minor));
+/// assert_eq!(classify_triad(&[0, 4, 7, 10]), None);
+/// ```
+pub fn classify_triad(semitones: &[usize]) -> Option<TriadQuality> {
+    let root = *semitones.first()?;
+    let mut values: Vec<usize> = semitones
+        .iter()
+        .map(|semitone| (semitone + 12 - root % 12) % 12)
+        .collect();
+    values.sort();
+    values.dedup();
+    match values.as_slice() {
+        [0, 4, 7] => Some(TriadQuality::Major),
+        [0, 3, 7] => Some(TriadQuality::Minor),
+        [0, 2, 7] => Some(TriadQuality::Sus2),
+        [0, 5, 7] => Some(TriadQuality::Sus4),
+        [0, 4, 8] => Some(TriadQuality::Augmented),
+        [0, 3, 6] => Some(TriadQuality::Diminished),
+        _ => None,
+    }
+}
@@ -99,14 +99,14 @@ impl Fraction {
     /// assert_ne!(two_quarters.get_numerator(), one_half.get_numerator());
     /// assert_ne!(two_quarters.get_denominator(), one_half.get_denominator());
     /// assert_eq!(two_quarters.get_as_float(), one_half.get_as_float());
-    /// 
+    ///
     /// let two_quarters_simplified = two_quarters.get_simplified();
     /// assert_eq!(two_quarters_simplified.get_numerator(), one_half.get_numerator());
     /// assert_eq!(two_quarters_simplified.get_denominator(), one_half.get_denominator());
     /// assert_eq!(two_quarters_simplified.get_as_float(), one_half.get_as_float());
     /// ```
     pub fn get_simplified(&self) -> Self {
-        let common_factor = gcd(self.numerator, self.denominator);
+        let common_factor = gcd(self.numerator as u64, self.denominator as u64) as u8;
         Self {
             numerator: self.numerator / common_factor,
             denominator: self.denominator / common_factor,
@@ -175,6 +175,56 @@ impl Beat {
     pub const SIXTEENTH_DOTTED: Self = Self::new(3, 32);
     /// The duration corresponding to a dotted thirty-second note.
     pub const THIRTYSECOND_DOTTED: Self = Self::new(3, 64);
+    /// Returns a new beat with the duration of the current beat with a dot, which is one and a half
+    /// times as long as the current beat.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Beat;
+    ///
+    /// assert_eq!(Beat::QUARTER.dotted(), Beat::QUARTER_DOTTED);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the numerator or denominator of the simplified result does not fit
+    /// in a [`u8`].
+    pub fn dotted(&self) -> Self {
+        self.scaled(3, 2)
+    }
+
+    /// Returns a new beat with the duration of the current beat with two dots, which is one and
+    /// three quarters times as long as the current beat.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Beat;
+    ///
+    /// assert_eq!(Beat::HALF.double_dotted(), Beat::new(7, 8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the numerator or denominator of the simplified result does not fit
+    /// in a [`u8`].
+    pub fn double_dotted(&self) -> Self {
+        self.scaled(7, 4)
+    }
+
+    fn scaled(&self, numerator: u64, denominator: u64) -> Self {
+        let numerator = self.numerator as u64 * numerator;
+        let denominator = self.denominator as u64 * denominator;
+        let common_factor = gcd(numerator, denominator);
+        match (
+            u8::try_from(numerator / common_factor),
+            u8::try_from(denominator / common_factor),
+        ) {
+            (Ok(numerator), Ok(denominator)) => Self::new(numerator, denominator),
+            _ => panic!("the resulting beat does not fit in a fraction of u8 values"),
+        }
+    }
 }
 
 /// A trait that defines a structure with a time duration for playing audio.
@@ -195,6 +245,82 @@ impl AudioDuration for Duration {
     }
 }
 
+/// A structure used to calculate a tempo in beats per minute from a series of taps, such as the
+/// taps of a key or a button. Only the most recent taps are used to calculate the tempo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TapTempo {
+    taps: Vec<Duration>,
+    window: usize,
+}
+
+impl TapTempo {
+    /// Creates a new tap tempo accumulator which keeps track of a limited number of taps.
+    ///
+    /// # Parameters
+    ///
+    /// - `window`: The maximum amount of recent taps used to calculate the tempo. Values below two
+    ///   are treated as two, since at least two taps are needed to measure an interval.
+    pub fn new(window: usize) -> Self {
+        Self {
+            taps: Vec::new(),
+            window: window.max(2),
+        }
+    }
+
+    /// Registers a new tap, discarding the oldest tap if the window is full.
+    ///
+    /// # Parameters
+    ///
+    /// - `instant`: A [`Duration`] representing the time at which the tap occurred, measured from
+    ///   any fixed point in time such as the start of the program.
+    pub fn tap(&mut self, instant: Duration) {
+        self.taps.push(instant);
+        if self.taps.len() > self.window {
+            self.taps.remove(0);
+        }
+    }
+
+    /// Returns an [`Option<f32>`] with the tempo in beats per minute obtained by averaging the
+    /// intervals between the recent taps, or [`None`] if less than two taps have been registered
+    /// or the taps did not advance in time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::TapTempo;
+    /// use std::time::Duration;
+    ///
+    /// let mut tap_tempo = TapTempo::new(4);
+    /// tap_tempo.tap(Duration::from_millis(0));
+    /// assert_eq!(tap_tempo.bpm(), None);
+    /// tap_tempo.tap(Duration::from_millis(1000));
+    /// assert_eq!(tap_tempo.bpm(), Some(60.0));
+    /// ```
+    pub fn bpm(&self) -> Option<f32> {
+        if self.taps.len() < 2 {
+            return None;
+        }
+        let first = self.taps[0];
+        let last = self.taps[self.taps.len() - 1];
+        if last <= first {
+            return None;
+        }
+        let average_interval = (last - first).as_secs_f32() / (self.taps.len() - 1) as f32;
+        Some(60.0 / average_interval)
+    }
+
+    /// Discards all the registered taps.
+    pub fn reset(&mut self) {
+        self.taps.clear();
+    }
+}
+
+impl Default for TapTempo {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
 /// This enum contains representations for the different modes or types of musical scales that can
 /// be distinguished or generated by the library.
 #[derive(Copy, Clone, Debug, Default, Eq)]
@@ -328,6 +454,22 @@ pub enum ScaleType {
     Whole,
     /// The chromatic scale, which consists of all twelve pitch classes separated by a semitone.
     Chromatic,
+    /// The augmented scale, which is a hexatonic scale containing an alternating pattern of minor
+    /// thirds followed by semitones, starting with a minor third.
+    Augmented,
+    /// The bebop dominant scale, which is an octatonic scale derived from the Mixolydian mode with
+    /// an added major seventh.
+    BebopDominant,
+    /// The bebop major scale, which is an octatonic scale derived from the major scale with an
+    /// added minor sixth.
+    BebopMajor,
+    /// The Hungarian minor scale, which is equal to the harmonic minor scale with an augmented
+    /// fourth instead of a perfect fourth.
+    HungarianMinor,
+    /// A scale built from a custom pattern of steps with
+    /// [`Scale::from_steps`](crate::scale::Scale::from_steps), which does not correspond to any of
+    /// the other types of scales.
+    Custom,
 }
 
 impl ScaleType {
@@ -373,6 +515,11 @@ impl ScaleType {
             ScaleType::MinorBlues => 26,
             ScaleType::Whole => 27,
             ScaleType::Chromatic => 28,
+            ScaleType::Augmented => 29,
+            ScaleType::BebopDominant => 30,
+            ScaleType::BebopMajor => 31,
+            ScaleType::HungarianMinor => 32,
+            ScaleType::Custom => 0,
         }
     }
 }
@@ -403,6 +550,82 @@ pub enum TriadQuality {
     Diminished,
 }
 
+/// This enum is used to represent the function of a note within a chord built from stacked thirds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChordFunction {
+    #[default]
+    /// The root of the chord, which is the note the rest of the chord is built on.
+    Root,
+    /// The third of the chord, which is a minor or major third above the root.
+    Third,
+    /// The fifth of the chord, which is a diminished, perfect or augmented fifth above the root.
+    Fifth,
+    /// The seventh of the chord, which is a diminished, minor or major seventh above the root.
+    Seventh,
+    /// The ninth of the chord, which is a minor, major or augmented ninth above the root.
+    Ninth,
+    /// The eleventh of the chord, which is a perfect or augmented eleventh above the root.
+    Eleventh,
+    /// The thirteenth of the chord, which is a minor or major thirteenth above the root.
+    Thirteenth,
+}
+
+impl ChordFunction {
+    /// Returns the values of the intervals in semitones that a note with this function can have
+    /// with respect to the root of the chord.
+    pub fn get_interval_values(&self) -> &'static [u64] {
+        match self {
+            ChordFunction::Root => &[0],
+            ChordFunction::Third => &[3, 4],
+            ChordFunction::Fifth => &[6, 7, 8],
+            ChordFunction::Seventh => &[9, 10, 11],
+            ChordFunction::Ninth => &[13, 14, 15],
+            ChordFunction::Eleventh => &[17, 18],
+            ChordFunction::Thirteenth => &[20, 21],
+        }
+    }
+}
+
+/// This enum is used to represent the type of a cadence, which is a progression of two chords that
+/// ends a phrase.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CadenceType {
+    /// An authentic cadence, which moves from the dominant chord (V) to the tonic chord (I).
+    Authentic,
+    /// A plagal cadence, which moves from the subdominant chord (IV) to the tonic chord (I).
+    Plagal,
+    /// A half cadence, which moves from any other chord to the dominant chord (V).
+    Half,
+    /// A deceptive cadence, which moves from the dominant chord (V) to the submediant chord (vi).
+    Deceptive,
+}
+
+/// This enum is used to represent a drop voicing, which is a voicing created by lowering one or
+/// more notes of a chord in close position by an octave, counting the notes from the top.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DropVoicing {
+    #[default]
+    /// The drop-2 voicing, where the second note from the top is lowered by an octave.
+    Drop2,
+    /// The drop-3 voicing, where the third note from the top is lowered by an octave.
+    Drop3,
+    /// The drop-2-and-4 voicing, where the second and fourth notes from the top are lowered by an
+    /// octave.
+    Drop2And4,
+}
+
+impl DropVoicing {
+    /// Returns the positions counting from the top of the chord of each of the notes that are
+    /// lowered by an octave in this voicing, where the top note is at position 1.
+    pub fn get_positions(&self) -> &'static [usize] {
+        match self {
+            DropVoicing::Drop2 => &[2],
+            DropVoicing::Drop3 => &[3],
+            DropVoicing::Drop2And4 => &[2, 4],
+        }
+    }
+}
+
 /// This enum is used to represent the type of a pentatonic.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum PentatonicType {
@@ -415,6 +638,58 @@ pub enum PentatonicType {
     Minor,
 }
 
+/// An enum that can be used to control the direction of arpeggiation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ArpeggioDirection {
+    /// The arpeggio ascends from the lowest to the highest note before looping back around to the
+    /// lowest note.
+    #[default]
+    Up,
+    /// The arpeggio descends from the highest to the lowest note before looping back around to the
+    /// highest note.
+    Down,
+    /// The arpeggio ascends from the lowest to the highest note, and once it reaches its peak
+    /// descends from the highest to the lowest note, essentially forming a cycle.
+    UpDown,
+}
+
+impl ArpeggioDirection {
+    /// Returns the indices of the elements that are played in an arpeggio in this direction,
+    /// given the amount of elements to arpeggiate and the total amount of elements to play.
+    pub(crate) fn get_indices(&self, length: usize, total: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::new();
+        if length == 0 {
+            return indices;
+        }
+        let mut updown_ascending: bool = true;
+        let mut current_index = match self {
+            ArpeggioDirection::Up => 0,
+            ArpeggioDirection::Down => length - 1,
+            ArpeggioDirection::UpDown => 0,
+        };
+        for _ in 0..total {
+            indices.push(current_index);
+            current_index = match self {
+                ArpeggioDirection::Up => (current_index + 1).rem_euclid(length),
+                ArpeggioDirection::Down => {
+                    (current_index as isize - 1).rem_euclid(length as isize) as usize
+                }
+                ArpeggioDirection::UpDown => match updown_ascending {
+                    true => (current_index + 1).rem_euclid(length),
+                    false => (current_index as isize - 1).rem_euclid(length as isize) as usize,
+                },
+            };
+            if !updown_ascending && current_index == 0 {
+                updown_ascending = true;
+            }
+            if updown_ascending && current_index == length - 1 {
+                updown_ascending = false;
+            }
+        }
+        indices
+    }
+}
+
 /// An error which is returned when a function receives an input that was not in the expected
 /// format.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -474,10 +749,36 @@ impl fmt::Display for IncompleteChordError {
     }
 }
 
-fn gcd(a: u8, b: u8) -> u8 {
+pub(crate) fn gcd(a: u64, b: u64) -> u64 {
     if b == 0 {
         a
     } else {
         gcd(b, a % b)
     }
 }
+
+/// A small pseudorandom number generator based on the xorshift algorithm, which is used to generate
+/// reproducible noise and random variations without depending on an external crate.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct XorShiftRng {
+    state: u64,
+}
+
+impl XorShiftRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ 0x9E3779B97F4A7C15,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
@@ -19,11 +19,7 @@ impl Interval {
     pub fn between_notes(first: Note, second: Note) -> Self {
         let first_value = first.get_value();
         let second_value = second.get_value();
-        let difference: u16 = if first_value <= second_value {
-            (second_value - first_value) as u16
-        } else {
-            (first_value - second_value) as u16
-        };
+        let difference: u16 = first_value.abs_diff(second_value);
         Self::from(difference)
     }
 
@@ -35,3 +35,13 @@ pub mod midi;
 
 /// The track module contains a structure which can be used to represent a MIDI file track.
 pub mod track;
+
+/// The analysis module contains functions which can be used to analyze musical structures such as
+/// voices and progressions.
+pub mod analysis;
+
+/// The melody module contains functions which can be used to generate and transform melodies.
+pub mod melody;
+
+/// The rhythm module contains a structure which can be used to represent a sequence of beats.
+pub mod rhythm;
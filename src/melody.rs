@@ -0,0 +1,161 @@
+use crate::common::{Beat, InputError, XorShiftRng};
+use crate::note::Note;
+use crate::rhythm::Rhythm;
+use crate::scale::Scale;
+
+/// A structure which holds a phrase, which is a melody where each note is paired with the beat of
+/// a rhythm that determines how long the note is played for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phrase {
+    notes: Vec<Note>,
+    rhythm: Rhythm,
+}
+
+impl Phrase {
+    /// Creates a phrase from a sequence of notes and a rhythm. This function returns a [`Result`]
+    /// which can contain the [`Phrase`] or an [`InputError`] if the amount of notes is not the
+    /// same as the amount of beats in the rhythm.
+    ///
+    /// # Parameters
+    ///
+    /// - `notes`: A slice of [`Note`] representing the melody of the phrase.
+    /// - `rhythm`: A [`Rhythm`] with a beat for each of the notes of the phrase.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::melody::Phrase;
+    /// use music_tools::note::Note;
+    /// use music_tools::rhythm::Rhythm;
+    /// use std::str::FromStr;
+    ///
+    /// let notes = ["C4", "G4"].map(|name| Note::from_str(name).unwrap());
+    /// let rhythm = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::HALF, Beat::HALF]);
+    /// let phrase = Phrase::try_new(&notes, rhythm).unwrap();
+    /// assert_eq!(phrase.iter().count(), 2);
+    /// ```
+    pub fn try_new(notes: &[Note], rhythm: Rhythm) -> Result<Self, InputError> {
+        if notes.len() != rhythm.get_beats().len() {
+            return Err(InputError {
+                message: "the phrase must have the same amount of notes as beats in the rhythm",
+            });
+        }
+        Ok(Self {
+            notes: notes.to_vec(),
+            rhythm,
+        })
+    }
+
+    /// Returns a vector of [`Note`] with the notes of the phrase.
+    pub fn get_notes(&self) -> Vec<Note> {
+        self.notes.clone()
+    }
+
+    /// Returns the [`Rhythm`] of the phrase.
+    pub fn get_rhythm(&self) -> Rhythm {
+        self.rhythm.clone()
+    }
+
+    /// Returns an iterator over the notes of the phrase, paired with the [`Beat`] that each note is
+    /// played for.
+    pub fn iter(&self) -> impl Iterator<Item = (Note, Beat)> + '_ {
+        self.notes.iter().copied().zip(self.rhythm.get_beats())
+    }
+}
+
+/// Returns the retrograde of a melody, which contains the same notes in reverse order.
+///
+/// # Parameters
+///
+/// - `notes`: A slice of [`Note`] representing the melody to reverse.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::melody::retrograde;
+/// use music_tools::note::Note;
+/// use std::str::FromStr;
+///
+/// let melody = ["C4", "D4"].map(|name| Note::from_str(name).unwrap());
+/// let reversed = ["D4", "C4"].map(|name| Note::from_str(name).unwrap());
+/// assert_eq!(retrograde(&melody), reversed);
+/// ```
+pub fn retrograde(notes: &[Note]) -> Vec<Note> {
+    notes.iter().rev().copied().collect()
+}
+
+/// Returns the inversion of a melody, where each note is reflected around an axis so that a note
+/// which was a certain amount of semitones above the axis is the same amount of semitones below it
+/// and vice versa.
+///
+/// # Parameters
+///
+/// - `notes`: A slice of [`Note`] representing the melody to invert.
+/// - `axis`: A reference to the [`Note`] that the melody will be reflected around.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::melody::invert_melody;
+/// use music_tools::note::Note;
+/// use std::str::FromStr;
+///
+/// let melody = ["C4", "E4", "G4"].map(|name| Note::from_str(name).unwrap());
+/// let inverted = ["C4", "Ab3", "F3"].map(|name| Note::from_str(name).unwrap());
+/// assert_eq!(invert_melody(&melody, &Note::from_str("C4").unwrap()), inverted);
+/// ```
+pub fn invert_melody(notes: &[Note], axis: &Note) -> Vec<Note> {
+    notes
+        .iter()
+        .map(|note| note.at_offset(2 * (axis.get_value() - note.get_value()) as isize))
+        .collect()
+}
+
+/// Returns a random melody made of the notes of a scale, which is generated from a seed so that
+/// the same seed always produces the same melody. Each note is chosen independently from the notes
+/// of the scale between the tonic and the tonic a given amount of octaves above it, both included.
+///
+/// # Parameters
+///
+/// - `scale`: A reference to the [`Scale`] that the notes are taken from.
+/// - `tonic`: A reference to the [`Note`] representing the tonic of the scale and the lowest note
+///   of the melody.
+/// - `length`: The amount of notes in the melody.
+/// - `range_octaves`: The amount of octaves above the tonic that the melody can reach, which is
+///   treated as 1 if it is 0.
+/// - `seed`: The seed used to generate the melody.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::common::{PentatonicType, ScaleType};
+/// use music_tools::melody::random_melody;
+/// use music_tools::note::Note;
+/// use music_tools::scale::Scale;
+/// use std::str::FromStr;
+///
+/// let scale = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+/// let tonic = Note::from_str("C4").unwrap();
+/// let melody = random_melody(&scale, &tonic, 8, 2, 42);
+/// assert_eq!(melody.len(), 8);
+/// assert!(melody.iter().all(|note| *note >= tonic));
+/// assert!(melody.iter().all(|note| *note <= Note::from_str("C6").unwrap()));
+/// ```
+pub fn random_melody(
+    scale: &Scale,
+    tonic: &Note,
+    length: usize,
+    range_octaves: u8,
+    seed: u64,
+) -> Vec<Note> {
+    let highest_value = tonic.get_value() + 12 * range_octaves.max(1) as i16;
+    let candidates: Vec<Note> = scale
+        .note_iter(*tonic)
+        .take_while(|note| note.get_value() <= highest_value)
+        .collect();
+    let mut rng = XorShiftRng::new(seed);
+    (0..length)
+        .map(|_| candidates[(rng.next_u64() % candidates.len() as u64) as usize])
+        .collect()
+}
@@ -1,14 +1,46 @@
+#[cfg(feature = "audio")]
+use crate::audio::common::Synth;
+#[cfg(feature = "audio")]
+use crate::audio::player::{AudioPlayer, BitsPerSample};
+use crate::chord::Chord;
 use crate::common::{Fraction, InputError};
 use crate::note::Note;
-use crate::track::Track;
+use crate::track::{Event, Track};
 use apres::MIDIEvent;
 use apres::MIDI as Apres_MIDI;
+#[cfg(feature = "audio")]
+use std::collections::HashMap;
+#[cfg(feature = "audio")]
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+/// A structure containing the options that can be used to customize how a MIDI file is imported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Whether to discard tracks which have exactly the same events as a previous track.
+    pub deduplicate_tracks: bool,
+    /// Whether to discard tracks which do not contain any notes, such as tracks which only contain
+    /// metadata.
+    pub drop_empty: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            deduplicate_tracks: false,
+            drop_empty: true,
+        }
+    }
+}
 
 /// A structure which holds a MIDI object that can be imported from or exported to a MIDI file,
 /// containing a set of [`Track`] objects.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct MIDI {
     tracks: Vec<Track>,
+    tempo_changes: Vec<(u64, u32)>,
 }
 
 impl MIDI {
@@ -24,6 +56,33 @@ impl MIDI {
     ///
     /// - `file_path`: A string of the path to the MIDI file to import.
     pub fn import_from_file(file_path: &str) -> Result<Self, InputError> {
+        Self::import_with_options(file_path, ImportOptions::default())
+    }
+
+    /// Imports a MIDI object from a MIDI file using a set of [`ImportOptions`] to decide which
+    /// tracks are kept. The return value is a [`Result`] which can be either a [`MIDI`] or an
+    /// [`InputError`] if the MIDI file provided does not exist or is invalid.
+    ///
+    /// # Parameters
+    ///
+    /// - `file_path`: A string of the path to the MIDI file to import.
+    /// - `options`: An [`ImportOptions`] structure containing the options used while importing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use music_tools::midi::{ImportOptions, MIDI};
+    ///
+    /// let options = ImportOptions {
+    ///     deduplicate_tracks: true,
+    ///     drop_empty: true,
+    /// };
+    /// let midi = MIDI::import_with_options("song.mid", options).unwrap();
+    /// ```
+    pub fn import_with_options(
+        file_path: &str,
+        options: ImportOptions,
+    ) -> Result<Self, InputError> {
         let midi_object = match Apres_MIDI::from_path(file_path) {
             Ok(apres_midi_object) => apres_midi_object,
             Err(_) => {
@@ -37,9 +96,12 @@ impl MIDI {
         let mut tracks: Vec<Track> = Vec::new();
         let mut tempo: f32 = 120.0;
         let mut time_signature: Fraction = Fraction::new(4, 4);
+        let mut tempo_changes: Vec<(u64, u32)> = Vec::new();
         for midi_track_info in midi_tracks {
             let mut track = Track::new_with_ticks(tempo, time_signature, ticks_per_quarter_note);
+            let mut current_tick: u64 = 0;
             for (delta_ticks, event_id) in midi_track_info {
+                current_tick += delta_ticks as u64;
                 let event = match midi_object.get_event(event_id) {
                     Some(event_object) => event_object,
                     None => continue,
@@ -47,9 +109,10 @@ impl MIDI {
                 match event {
                     MIDIEvent::NoteOn(_channel, note_index, velocity) => {
                         if velocity > 0 {
-                            track.add_event(
+                            track.add_event_with_velocity(
                                 Note::from_midi_index(note_index).unwrap(),
                                 true,
+                                velocity,
                                 delta_ticks as u64,
                             );
                         } else {
@@ -77,19 +140,85 @@ impl MIDI {
                     }
                     MIDIEvent::SetTempo(us_per_quarter_note) => {
                         tempo = 60000000.0 / us_per_quarter_note as f32;
+                        tempo_changes.push((current_tick, us_per_quarter_note));
+                    }
+                    MIDIEvent::ProgramChange(_channel, program) => {
+                        track.set_program(Some(program));
                     }
                     _ => {}
                 }
             }
-            if track.get_duration() > 0 {
-                tracks.push(track);
+            if options.drop_empty && track.get_duration() == 0 {
+                continue;
+            }
+            if options.deduplicate_tracks
+                && tracks
+                    .iter()
+                    .any(|other| other.get_events() == track.get_events())
+            {
+                continue;
             }
+            tracks.push(track);
         }
         for track in &mut tracks {
             track.set_time_signature(time_signature);
             track.set_tempo(tempo);
         }
-        Ok(Self { tracks })
+        tempo_changes.sort_by_key(|(tick, _)| *tick);
+        Ok(Self {
+            tracks,
+            tempo_changes,
+        })
+    }
+
+    /// Imports a MIDI object from a MIDI file keeping only the tracks at the given indices, in the
+    /// order they appear in the file. The indices refer to the tracks of the file itself, including
+    /// tracks without notes, so in a file where the first track only contains metadata such as the
+    /// tempo, the first track with notes has an index of 1. The return value is a [`Result`] which
+    /// can be either a [`MIDI`] or an [`InputError`] if the MIDI file provided does not exist or is
+    /// invalid, if any of the indices is out of range or if an index is repeated.
+    ///
+    /// # Parameters
+    ///
+    /// - `file_path`: A string of the path to the MIDI file to import.
+    /// - `indices`: A slice with the indices of the tracks to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use music_tools::midi::MIDI;
+    ///
+    /// let midi = MIDI::import_tracks("song.mid", &[1, 3]).unwrap();
+    /// assert_eq!(midi.get_num_tracks(), 2);
+    /// ```
+    pub fn import_tracks(file_path: &str, indices: &[usize]) -> Result<Self, InputError> {
+        let options = ImportOptions {
+            deduplicate_tracks: false,
+            drop_empty: false,
+        };
+        let mut midi = Self::import_with_options(file_path, options)?;
+        if indices.iter().any(|index| *index >= midi.tracks.len()) {
+            return Err(InputError {
+                message: "the track indices must be lower than the amount of tracks in the file",
+            });
+        }
+        if indices
+            .iter()
+            .enumerate()
+            .any(|(position, index)| indices[..position].contains(index))
+        {
+            return Err(InputError {
+                message: "the track indices must not be repeated",
+            });
+        }
+        midi.tracks = midi
+            .tracks
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| indices.contains(index))
+            .map(|(_, track)| track)
+            .collect();
+        Ok(midi)
     }
 
     /// Exports a MIDI object to a MIDI file. The function returns a [`Result`] which can be an
@@ -115,10 +244,18 @@ impl MIDI {
         let midi_denom = f64::log2(time_signature.get_denominator() as f64) as u8;
         midi_object.set_ppqn(ppqn);
         midi_object.insert_event(0, 0, MIDIEvent::TimeSignature(midi_num, midi_denom, 24, 8));
-        midi_object.insert_event(0, 0, MIDIEvent::SetTempo(us_per_quarter_note));
+        if self.tempo_changes.first().is_none_or(|(tick, _)| *tick > 0) {
+            midi_object.insert_event(0, 0, MIDIEvent::SetTempo(us_per_quarter_note));
+        }
+        for (tick, us_per_quarter_note) in &self.tempo_changes {
+            midi_object.insert_event(0, *tick as usize, MIDIEvent::SetTempo(*us_per_quarter_note));
+        }
         let mut track_index = 1;
         for track in &mut self.tracks {
             let mut current_tick = 0;
+            if let Some(program) = track.get_program() {
+                midi_object.insert_event(track_index, 0, MIDIEvent::ProgramChange(0, program));
+            }
             while let Some(event) = track.get_next_event() {
                 let note_option = event.get_note().get_midi_index();
                 if note_option.is_none() {
@@ -126,7 +263,7 @@ impl MIDI {
                 }
                 let note_index = note_option.unwrap();
                 let midi_event: MIDIEvent = if event.is_active() {
-                    MIDIEvent::NoteOn(0, note_index, 100)
+                    MIDIEvent::NoteOn(0, note_index, event.get_velocity())
                 } else {
                     MIDIEvent::NoteOff(0, note_index, 0)
                 };
@@ -140,6 +277,215 @@ impl MIDI {
         Ok(())
     }
 
+    /// Renders the MIDI object into a WAV file without playing it, so no audio device is needed.
+    /// The synthesizers are assigned to the tracks in order, wrapping around if there are more
+    /// tracks than synthesizers. The function returns a [`Result`] which can contain an error if
+    /// the WAV file could not be written.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: A string representing the path of the WAV file to generate.
+    /// - `synths`: A slice of synthesizers that will be used to play the tracks of the MIDI object,
+    ///   which must implement the [`Synth`] trait. If the slice is empty the WAV file has no audio.
+    /// - `bits_per_sample`: A [`BitsPerSample`] enum representing the amount of bits per sample to
+    ///   be stored in the WAV file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use music_tools::audio::player::BitsPerSample;
+    /// use music_tools::audio::wavetable::WavetableOscillator;
+    /// use music_tools::midi::MIDI;
+    ///
+    /// let midi = MIDI::import_from_file("song.mid").unwrap();
+    /// let oscillator = WavetableOscillator::default();
+    /// midi.render_to_wav("song.wav", &[oscillator], BitsPerSample::SIXTEEN).unwrap();
+    /// ```
+    #[cfg(feature = "audio")]
+    pub fn render_to_wav(
+        &self,
+        path: &str,
+        synths: &[impl Synth + Clone + 'static],
+        bits_per_sample: BitsPerSample,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut player = AudioPlayer::new_offline();
+        player.push_midi_with_synths(self, synths, &HashMap::new(), None);
+        player.export_wav(path, bits_per_sample)
+    }
+
+    /// Returns a vector of tuples containing the index of a track, an [`Event`] of that track and a
+    /// [`Duration`] representing the absolute time at which the event occurs, for every event in
+    /// the MIDI object. The times start at a base tempo and follow the tempo changes of the MIDI
+    /// object as given by [`MIDI::get_tempo_map`]. The tuples are sorted by the time at which the
+    /// events occur, and events that occur at the same time keep the order of their tracks.
+    ///
+    /// # Parameters
+    ///
+    /// - `base_tempo`: An [`f32`] representing the tempo in beats per minute that is used before
+    ///   the first tempo change of the MIDI object, or for the whole MIDI object if it has no tempo
+    ///   changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::midi::MIDI;
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    /// use std::time::Duration;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::HALF);
+    /// let mut midi = MIDI::new();
+    /// midi.add_track(track);
+    /// let events = midi.events_with_time(60.0);
+    /// assert_eq!(events[1].2, Duration::from_secs(2));
+    /// ```
+    pub fn events_with_time(&self, base_tempo: f32) -> Vec<(usize, Event, Duration)> {
+        let ticks_per_quarter_note =
+            self.tracks
+                .first()
+                .map_or(360, |track| track.get_ticks_per_quarter_note()) as f64;
+        //Each tempo as the position in quarter notes where it starts, the time in seconds where it
+        //starts and the length of a quarter note in seconds
+        let mut tempo_map: Vec<(f64, f64, f64)> = vec![(0.0, 0.0, 60.0 / base_tempo as f64)];
+        for ((tick, _), (time, us_per_quarter_note)) in self
+            .tempo_changes
+            .iter()
+            .zip(self.get_tempo_map(base_tempo))
+        {
+            tempo_map.push((
+                *tick as f64 / ticks_per_quarter_note,
+                time.as_secs_f64(),
+                us_per_quarter_note as f64 / 1000000.0,
+            ));
+        }
+        let mut timed_events: Vec<(usize, Event, Duration)> = Vec::new();
+        for (track_index, track) in self.tracks.iter().enumerate() {
+            let ticks_per_quarter_note = track.get_ticks_per_quarter_note() as f64;
+            let mut current_tick = 0;
+            for event in track.get_events() {
+                current_tick += event.get_delta_ticks();
+                let quarter_notes = current_tick as f64 / ticks_per_quarter_note;
+                let (start, start_seconds, quarter_seconds) = tempo_map
+                    .iter()
+                    .rev()
+                    .find(|(start, _, _)| *start <= quarter_notes)
+                    .unwrap_or(&tempo_map[0]);
+                let seconds = start_seconds + (quarter_notes - start) * quarter_seconds;
+                timed_events.push((track_index, event, Duration::from_secs_f64(seconds)));
+            }
+        }
+        timed_events.sort_by_key(|(_, _, time)| *time);
+        timed_events
+    }
+
+    /// Returns an [`Option<Chord>`] with the chord formed by all the notes that are sounding across
+    /// all the tracks at a given time, or [`None`] if no notes are sounding at that time. The
+    /// lowest sounding note is used as the tonic of the chord, and a note that is sounding in more
+    /// than one track is only added to the chord once. A note sounds from the time of its note on
+    /// event until the time of its note off event, so a note that starts exactly at the given time
+    /// is sounding while a note that ends exactly at the given time is not.
+    ///
+    /// # Parameters
+    ///
+    /// - `time`: A [`Duration`] representing the time from the start of the MIDI object.
+    /// - `base_tempo`: An [`f32`] representing the tempo in beats per minute that is used before
+    ///   the first tempo change of the MIDI object, or for the whole MIDI object if it has no tempo
+    ///   changes.
+    pub fn chord_at(&self, time: Duration, base_tempo: f32) -> Option<Chord> {
+        let mut active_notes: Vec<Note> = Vec::new();
+        for (_, event, event_time) in self.events_with_time(base_tempo) {
+            if event_time > time {
+                break;
+            }
+            if event.is_active() {
+                active_notes.push(event.get_note());
+            } else if let Some(index) = active_notes
+                .iter()
+                .position(|note| *note == event.get_note())
+            {
+                active_notes.remove(index);
+            }
+        }
+        if active_notes.is_empty() {
+            return None;
+        }
+        active_notes.sort();
+        active_notes.dedup();
+        Some(Chord::from(active_notes.as_slice()))
+    }
+
+    /// Returns a vector with every [`Note`] that is played across all the tracks of the MIDI object
+    /// and lies within an inclusive range of notes, sorted by the time at which each note starts.
+    ///
+    /// # Parameters
+    ///
+    /// - `low`: The lowest [`Note`] of the range.
+    /// - `high`: The highest [`Note`] of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::midi::MIDI;
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C2").unwrap(), Beat::QUARTER);
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    /// let mut midi = MIDI::new();
+    /// midi.add_track(track);
+    /// let notes = midi.notes_in_range(Note::from_str("C3").unwrap(), Note::from_str("C5").unwrap());
+    /// assert_eq!(notes, vec![Note::from_str("C4").unwrap()]);
+    /// ```
+    pub fn notes_in_range(&self, low: Note, high: Note) -> Vec<Note> {
+        let base_tempo = self.tracks.first().map_or(120.0, |track| track.get_tempo());
+        self.events_with_time(base_tempo)
+            .into_iter()
+            .filter(|(_, event, _)| event.is_active())
+            .map(|(_, event, _)| event.get_note())
+            .filter(|note| *note >= low && *note <= high)
+            .collect()
+    }
+
+    /// Returns an array with the total amount of whole notes that each pitch class sounds for
+    /// across all of the tracks of the MIDI object, indexed by the value of the pitch class
+    /// starting from C. The durations use the same unit as [`Fraction::get_as_float`], so a quarter
+    /// note adds 0.25. Notes that overlap each other are all counted, so two notes of the same
+    /// pitch class that sound at the same time add both of their durations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::midi::MIDI;
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("E4").unwrap(), Beat::HALF);
+    /// track.add_note(Note::from_str("E5").unwrap(), Beat::QUARTER);
+    /// let mut midi = MIDI::new();
+    /// midi.add_track(track);
+    /// assert_eq!(midi.pitch_class_durations()[4], 0.75);
+    /// ```
+    pub fn pitch_class_durations(&self) -> [f32; 12] {
+        let mut totals = [0.0_f64; 12];
+        for track in &self.tracks {
+            let ticks_per_whole_note = 4.0 * track.get_ticks_per_quarter_note() as f64;
+            for (note, _, duration_ticks) in track.note_spans() {
+                totals[note.get_pitch_class().get_value() as usize] +=
+                    duration_ticks as f64 / ticks_per_whole_note;
+            }
+        }
+        totals.map(|total| total as f32)
+    }
+
     /// Adds a [`Track`] to the MIDI object.
     ///
     /// # Parameters
@@ -149,6 +495,67 @@ impl MIDI {
         self.tracks.push(track);
     }
 
+    /// Adds a change of tempo to the MIDI object, which is written as a SetTempo event when the
+    /// MIDI object is exported.
+    ///
+    /// # Parameters
+    ///
+    /// - `ticks`: The absolute time in MIDI ticks from the start of the MIDI object at which the
+    ///   tempo changes.
+    /// - `us_per_quarter_note`: The new tempo in microseconds per quarter note.
+    pub fn add_tempo_change(&mut self, ticks: u64, us_per_quarter_note: u32) {
+        let index = self
+            .tempo_changes
+            .partition_point(|(other_ticks, _)| *other_ticks <= ticks);
+        self.tempo_changes
+            .insert(index, (ticks, us_per_quarter_note));
+    }
+
+    /// Returns a vector with each change of tempo of the MIDI object in the order they occur,
+    /// paired with the absolute time at which they occur. Each tempo is given in microseconds per
+    /// quarter note, and the time of each change is calculated using the tempos before it.
+    ///
+    /// # Parameters
+    ///
+    /// - `base_tempo`: An [`f32`] representing the tempo in beats per minute before the first tempo
+    ///   change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Fraction;
+    /// use music_tools::midi::MIDI;
+    /// use music_tools::track::Track;
+    /// use std::time::Duration;
+    ///
+    /// let mut midi = MIDI::new();
+    /// midi.add_track(Track::new(120.0, Fraction::new(4, 4)));
+    /// midi.add_tempo_change(0, 500000);
+    /// midi.add_tempo_change(720, 1000000);
+    /// assert_eq!(
+    ///     midi.get_tempo_map(120.0),
+    ///     vec![(Duration::ZERO, 500000), (Duration::from_secs(1), 1000000)]
+    /// );
+    /// ```
+    pub fn get_tempo_map(&self, base_tempo: f32) -> Vec<(Duration, u32)> {
+        let ticks_per_quarter_note =
+            self.tracks
+                .first()
+                .map_or(360, |track| track.get_ticks_per_quarter_note()) as u64;
+        let mut tempo_map: Vec<(Duration, u32)> = Vec::new();
+        let mut current_us: u64 = 0;
+        let mut previous_tick: u64 = 0;
+        let mut previous_us_per_quarter_note = (60000000.0 / base_tempo) as u64;
+        for (tick, us_per_quarter_note) in &self.tempo_changes {
+            current_us +=
+                (tick - previous_tick) * previous_us_per_quarter_note / ticks_per_quarter_note;
+            tempo_map.push((Duration::from_micros(current_us), *us_per_quarter_note));
+            previous_tick = *tick;
+            previous_us_per_quarter_note = *us_per_quarter_note as u64;
+        }
+        tempo_map
+    }
+
     /// Returns a vector of [`Track`] with the tracks of the MIDI object.
     pub fn get_tracks(&self) -> Vec<Track> {
         self.tracks.clone()
@@ -159,3 +566,184 @@ impl MIDI {
         self.tracks.len()
     }
 }
+
+/// A structure which writes the events of a MIDI file to disk as they are produced, so that long
+/// compositions can be generated without holding every event in memory. The tracks must be
+/// written one after another, and the file is completed by calling [`MidiWriter::finish`]. The
+/// files written are read back by [`MIDI::import_from_file`] in the same way as the files
+/// written by [`MIDI::export_to_file`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use music_tools::common::Fraction;
+/// use music_tools::midi::MidiWriter;
+/// use music_tools::note::Note;
+/// use music_tools::track::Event;
+///
+/// let mut writer = MidiWriter::try_new("scale.mid", 120.0, Fraction::new(4, 4), 360).unwrap();
+/// for index in 60..72 {
+///     let note = Note::from_midi_index(index).unwrap();
+///     writer.write_event(0, 0, &Event::new(note, true, 100)).unwrap();
+///     writer.write_event(0, 360, &Event::new(note, false, 0)).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// ```
+pub struct MidiWriter {
+    file: BufWriter<File>,
+    num_tracks: u16,
+    current_track: Option<usize>,
+    track_length_position: u64,
+    track_length: u32,
+}
+
+impl MidiWriter {
+    /// Creates a MIDI file and writes its header along with a track containing the tempo and time
+    /// signature. The return value is a [`Result`] which can be either a [`MidiWriter`] or an
+    /// [`InputError`] if the file could not be written.
+    ///
+    /// # Parameters
+    ///
+    /// - `file_path`: A string of the path to save the MIDI file to.
+    /// - `tempo`: The tempo of the MIDI file in beats per minute.
+    /// - `time_signature`: A [`Fraction`] representing the time signature of the MIDI file.
+    /// - `ticks_per_quarter_note`: The amount of MIDI ticks in a quarter note.
+    pub fn try_new(
+        file_path: &str,
+        tempo: f32,
+        time_signature: Fraction,
+        ticks_per_quarter_note: u16,
+    ) -> Result<Self, InputError> {
+        let file = File::create(file_path).map_err(|_| InputError {
+            message: "the midi file could not be created",
+        })?;
+        let mut writer = Self {
+            file: BufWriter::new(file),
+            num_tracks: 0,
+            current_track: None,
+            track_length_position: 0,
+            track_length: 0,
+        };
+        let us_per_quarter_note = ((60000000.0 / tempo) as u32).to_be_bytes();
+        let midi_denom = f64::log2(time_signature.get_denominator() as f64) as u8;
+        let mut header: Vec<u8> = b"MThd".to_vec();
+        header.extend(6u32.to_be_bytes());
+        header.extend(1u16.to_be_bytes()); //Multiple simultaneous tracks
+        header.extend(0u16.to_be_bytes()); //Amount of tracks, written when finished
+        header.extend(ticks_per_quarter_note.to_be_bytes());
+        writer.write_bytes(&header)?;
+        writer.start_track()?;
+        writer.write_track_bytes(&[0x00, 0xFF, 0x58, 0x04, time_signature.get_numerator()])?;
+        writer.write_track_bytes(&[midi_denom, 24, 8])?;
+        writer.write_track_bytes(&[0x00, 0xFF, 0x51, 0x03])?;
+        writer.write_track_bytes(&us_per_quarter_note[1..])?;
+        writer.end_track()?;
+        Ok(writer)
+    }
+
+    /// Writes an event to one of the tracks of the MIDI file. Tracks are numbered from zero and
+    /// must be written in order, so once an event has been written to a track no more events can
+    /// be written to the tracks before it. Any tracks that are skipped are left empty. The return
+    /// value is a [`Result`] which can be an [`InputError`] if the track comes before the current
+    /// track, if the note of the event is outside of the MIDI range, if the delta ticks are too
+    /// large to be stored or if the event could not be written.
+    ///
+    /// # Parameters
+    ///
+    /// - `track`: The index of the track to write the event to.
+    /// - `delta_ticks`: The amount of MIDI ticks between the previous event of the track and the
+    ///   event.
+    /// - `event`: A reference to the [`Event`] to write.
+    pub fn write_event(
+        &mut self,
+        track: usize,
+        delta_ticks: u64,
+        event: &Event,
+    ) -> Result<(), InputError> {
+        if self.current_track.is_some_and(|current| track < current) {
+            return Err(InputError {
+                message: "the tracks of the midi file must be written in order",
+            });
+        }
+        if delta_ticks > 0x0FFFFFFF {
+            return Err(InputError {
+                message: "the delta ticks of a midi event must be lower than 2^28",
+            });
+        }
+        let note_index = event.get_note().get_midi_index().ok_or(InputError {
+            message: "the note of the event must be within the midi range",
+        })?;
+        while self.current_track != Some(track) {
+            if self.current_track.is_some() {
+                self.end_track()?;
+            }
+            self.current_track = Some(self.current_track.map_or(0, |current| current + 1));
+            self.start_track()?;
+        }
+        let mut bytes: Vec<u8> = Vec::new();
+        for shift in (1..4).rev() {
+            let group = (delta_ticks >> (7 * shift)) & 0x7F;
+            if delta_ticks >> (7 * shift) > 0 {
+                bytes.push(group as u8 | 0x80);
+            }
+        }
+        bytes.push((delta_ticks & 0x7F) as u8);
+        if event.is_active() {
+            bytes.extend([0x90, note_index, event.get_velocity()]);
+        } else {
+            bytes.extend([0x80, note_index, 0]);
+        }
+        self.write_track_bytes(&bytes)
+    }
+
+    /// Completes the last track and the header of the MIDI file and flushes it to disk. The return
+    /// value is a [`Result`] which can be an [`InputError`] if the file could not be written.
+    pub fn finish(mut self) -> Result<(), InputError> {
+        if self.current_track.is_some() {
+            self.end_track()?;
+        }
+        let num_tracks = self.num_tracks.to_be_bytes();
+        self.seek(SeekFrom::Start(10))?;
+        self.write_bytes(&num_tracks)?;
+        self.file.flush().map_err(|_| InputError {
+            message: "the midi file could not be written",
+        })
+    }
+
+    fn start_track(&mut self) -> Result<(), InputError> {
+        self.write_bytes(b"MTrk")?;
+        self.track_length_position = self.file.stream_position().map_err(|_| InputError {
+            message: "the midi file could not be written",
+        })?;
+        self.track_length = 0;
+        self.write_bytes(&[0; 4])?;
+        self.num_tracks += 1;
+        Ok(())
+    }
+
+    fn end_track(&mut self) -> Result<(), InputError> {
+        self.write_track_bytes(&[0x00, 0xFF, 0x2F, 0x00])?; //End of track
+        let track_length = self.track_length.to_be_bytes();
+        self.seek(SeekFrom::Start(self.track_length_position))?;
+        self.write_bytes(&track_length)?;
+        self.seek(SeekFrom::End(0))?;
+        Ok(())
+    }
+
+    fn write_track_bytes(&mut self, bytes: &[u8]) -> Result<(), InputError> {
+        self.track_length += bytes.len() as u32;
+        self.write_bytes(bytes)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), InputError> {
+        self.file.write_all(bytes).map_err(|_| InputError {
+            message: "the midi file could not be written",
+        })
+    }
+
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, InputError> {
+        self.file.seek(position).map_err(|_| InputError {
+            message: "the midi file could not be written",
+        })
+    }
+}
@@ -50,13 +50,65 @@ impl Note {
     ///
     /// - `index`: The index of the midi note, which can be any number between 0 and 127 inclusive.
     pub fn from_midi_index(index: u8) -> Result<Self, InputError> {
+        let midi_index_error = InputError {
+            message: "the midi index must be an integer between 0 and 127",
+        };
         if index > 127 {
+            return Err(midi_index_error);
+        }
+        let pitch_class = PitchClass::from_value(index % 12).ok_or(midi_index_error)?;
+        let octave = (index / 12) as i8 - 1;
+        Ok(Self {
+            pitch_class,
+            octave,
+            base_frequency: 440.0,
+        })
+    }
+
+    /// Constructs a [`Note`] from the letter of its pitch class, an amount of accidentals and an
+    /// octave without parsing a string. The function returns a [`Result`] which can contain the
+    /// note or an [`InputError`] if the letter is not between A and G or if there are more than two
+    /// accidentals. As with notes parsed from strings, the accidentals do not change the octave, so
+    /// for example B♯4 is the same note as C4.
+    ///
+    /// # Parameters
+    ///
+    /// - `letter`: An uppercase letter from A to G representing the natural pitch class.
+    /// - `accidental`: The amount of sharps if positive or flats if negative, between -2 and 2.
+    /// - `octave`: The octave of the note.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let note = Note::from_parts('F', 1, 4).unwrap();
+    /// assert_eq!(note, Note::from_str("F#4").unwrap());
+    /// ```
+    pub fn from_parts(letter: char, accidental: isize, octave: i8) -> Result<Self, InputError> {
+        let natural_value: isize = match letter {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => {
+                return Err(InputError {
+                    message: "the letter of the note must be an uppercase letter from A to G",
+                })
+            }
+        };
+        if !(-2..=2).contains(&accidental) {
             return Err(InputError {
-                message: "the midi index must be an integer between 0 and 127",
+                message: "the note cannot have more than two sharps or flats",
             });
         }
-        let pitch_class = PitchClass::try_from(index % 12).unwrap();
-        let octave = (index / 12) as i8 - 1;
+        let pitch_class =
+            PitchClass::from_chromatic_index((natural_value + accidental).rem_euclid(12) as usize)
+                .unwrap();
         Ok(Self {
             pitch_class,
             octave,
@@ -70,7 +122,7 @@ impl Note {
     /// # Parameters
     ///
     /// - `offset`: A signed integer representing the offset of the new note to return from the
-    /// current one.
+    ///   current one.
     pub fn at_offset(&self, offset: isize) -> Self {
         let pitch_class_val = self.pitch_class.get_value() as isize + offset;
         Self {
@@ -100,6 +152,42 @@ impl Note {
         }
     }
 
+    /// Returns a [`Result`] which can contain a tuple with the [`Note`] which is closest to a given
+    /// frequency and the deviation of the frequency from that note in cents, or an [`InputError`]
+    /// if the frequency or the reference frequency are not positive. The returned note uses the
+    /// given reference frequency for A4.
+    ///
+    /// # Parameters
+    ///
+    /// - `frequency`: The frequency in hertz to find the nearest note of.
+    /// - `base_frequency`: The reference frequency in hertz of the note A4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::note::Note;
+    /// use std::str::FromStr;
+    ///
+    /// let (note, cents) = Note::from_frequency(445.0, 440.0).unwrap();
+    /// assert_eq!(note, Note::from_str("A4").unwrap());
+    /// assert!(cents > 19.0 && cents < 20.0);
+    /// ```
+    pub fn from_frequency(frequency: f32, base_frequency: f32) -> Result<(Self, f32), InputError> {
+        if frequency <= 0.0 || base_frequency <= 0.0 {
+            return Err(InputError {
+                message: "the frequencies provided must be positive",
+            });
+        }
+        let semitones = 12.0 * (frequency / base_frequency).log2();
+        let nearest = semitones.round();
+        let mut a4 = Self::new(PitchClass::A, 4);
+        a4.set_base_frequency(base_frequency);
+        Ok((
+            a4.at_offset(nearest as isize),
+            100.0 * (semitones - nearest),
+        ))
+    }
+
     /// Changes the reference frequency of A4 to a specific value for this note, which will affect
     /// the frequency of the pitch class and octave when calculated. The default value for this
     /// frequency is equal to 440 hertz.
@@ -151,6 +239,29 @@ impl Note {
             )
     }
 
+    /// Returns the frequency in hertz that the current note would have with a different reference
+    /// frequency for the note A4, without modifying the reference frequency of the note.
+    ///
+    /// # Parameters
+    ///
+    /// - `a4_hz`: The reference frequency in hertz of the note A4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let note = Note::new(PitchClass::A, 3);
+    /// assert_eq!(note.get_frequency_at_reference(432.0), 216.0);
+    /// assert_eq!(note.get_frequency(), 220.0);
+    /// ```
+    pub fn get_frequency_at_reference(&self, a4_hz: f32) -> f32 {
+        let mut note = *self;
+        note.set_base_frequency(a4_hz);
+        note.get_frequency()
+    }
+
     /// Returns the octave of the current note.
     pub fn get_octave(&self) -> i8 {
         self.octave
@@ -173,6 +284,31 @@ impl Note {
         names
     }
 
+    /// Returns the most common name of the note, using a flat or a sharp for notes that do not
+    /// correspond to a natural note depending on the preference given.
+    ///
+    /// # Parameters
+    ///
+    /// - `prefer_flats`: A boolean which if set to true spells notes such as A sharp 4 as B♭4, or
+    ///   if set to false spells them as A♯4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::note::Note;
+    ///
+    /// let note = Note::from_midi_index(70).unwrap();
+    /// assert_eq!(note.get_spelled_name(true), "B♭4");
+    /// assert_eq!(note.get_spelled_name(false), "A♯4");
+    /// ```
+    pub fn get_spelled_name(&self, prefer_flats: bool) -> String {
+        format!(
+            "{}{}",
+            self.pitch_class.get_spelled_name(prefer_flats),
+            self.octave
+        )
+    }
+
     /// Returns a numerical value representing the position of the note with respect to C0. If a key
     /// is below C0, then this function will return a negative integer representing that note, or if
     /// it is above then the function will return a positive integer.
@@ -234,6 +370,40 @@ impl Note {
         }
         Some(midi_index as u8)
     }
+
+    /// Returns an [`Option<u8>`] with the note packed into a single byte, or [`None`] if the note
+    /// cannot be packed. The lower four bits of the byte hold the chromatic index of the pitch
+    /// class and the upper four bits hold the octave plus one, so only notes between the octaves
+    /// -1 and 14 inclusive can be packed. The base frequency of the note is not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// let note = Note::new(PitchClass::F_SHARP, 4);
+    /// assert_eq!(Some(0x56), note.to_compact_u8());
+    /// assert_eq!(None, Note::new(PitchClass::C, 15).to_compact_u8());
+    /// ```
+    pub fn to_compact_u8(&self) -> Option<u8> {
+        if !(-1..=14).contains(&self.octave) {
+            return None;
+        }
+        Some((((self.octave + 1) as u8) << 4) | self.pitch_class.chromatic_index() as u8)
+    }
+
+    /// Returns an [`Option<Note>`] with the note that was packed into a byte by the
+    /// [`Note::to_compact_u8`] function, or [`None`] if the lower four bits of the byte do not
+    /// represent a valid pitch class.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The byte containing the packed note.
+    pub fn from_compact_u8(value: u8) -> Option<Self> {
+        let pitch_class = PitchClass::from_chromatic_index((value & 0x0F) as usize)?;
+        Some(Self::new(pitch_class, (value >> 4) as i8 - 1))
+    }
 }
 
 impl Default for Note {
@@ -320,3 +490,137 @@ impl TryFrom<Chord> for Vec<Note> {
         Ok(notes)
     }
 }
+
+/// Returns the interval in cents between two frequencies, which is positive if the second
+/// frequency is higher than the first and negative if it is lower. An octave is equal to 1200
+/// cents and an equal tempered semitone is equal to 100 cents.
+///
+/// # Parameters
+///
+/// - `a`: The first frequency in hertz.
+/// - `b`: The second frequency in hertz.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::note::cents_between;
+///
+/// assert_eq!(cents_between(440.0, 880.0), 1200.0);
+/// assert_eq!(cents_between(440.0, 220.0), -1200.0);
+/// ```
+pub fn cents_between(a: f32, b: f32) -> f32 {
+    1200.0 * (b / a).log2()
+}
+
+/// Returns true if two frequencies are within a certain amount of cents of each other, or false
+/// if otherwise.
+///
+/// # Parameters
+///
+/// - `a`: The first frequency in hertz.
+/// - `b`: The second frequency in hertz.
+/// - `tolerance_cents`: The maximum distance in cents between both frequencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::note::approx_equal_cents;
+///
+/// assert!(approx_equal_cents(440.0, 441.0, 5.0));
+/// assert!(!approx_equal_cents(440.0, 466.16, 5.0));
+/// ```
+pub fn approx_equal_cents(a: f32, b: f32, tolerance_cents: f32) -> bool {
+    cents_between(a, b).abs() <= tolerance_cents
+}
+
+/// Returns the frequency in hertz of a MIDI note number without constructing a [`Note`], where
+/// the note number 69 corresponds to A4.
+///
+/// # Parameters
+///
+/// - `note`: The MIDI note number between 0 and 127.
+/// - `base_frequency`: The reference frequency in hertz of the note A4.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::note::midi_note_to_frequency;
+///
+/// assert_eq!(midi_note_to_frequency(69, 440.0), 440.0);
+/// assert_eq!(midi_note_to_frequency(81, 432.0), 864.0);
+/// ```
+pub fn midi_note_to_frequency(note: u8, base_frequency: f32) -> f32 {
+    base_frequency * 2.0_f32.powf((note as f32 - 69.0) / 12.0)
+}
+
+/// Returns a [`Result`] which can contain a vector with the first harmonics of the overtone series
+/// of a fundamental frequency, or an [`InputError`] if the fundamental frequency is not positive.
+/// Each harmonic is represented by a tuple with its frequency in hertz, the nearest equal
+/// tempered [`Note`] with a reference frequency of 440 hertz for A4 and the deviation of the
+/// harmonic from that note in cents. The first harmonic is the fundamental frequency itself.
+///
+/// # Parameters
+///
+/// - `fundamental_hz`: The fundamental frequency in hertz.
+/// - `count`: The amount of harmonics to return.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::note::{overtone_series, Note};
+/// use std::str::FromStr;
+///
+/// let harmonics = overtone_series(110.0, 7).unwrap();
+/// let (frequency, note, cents) = harmonics[6];
+/// assert_eq!(frequency, 770.0);
+/// assert_eq!(note, Note::from_str("G5").unwrap());
+/// assert!(cents < -30.0);
+/// ```
+pub fn overtone_series(
+    fundamental_hz: f32,
+    count: usize,
+) -> Result<Vec<(f32, Note, f32)>, InputError> {
+    if fundamental_hz <= 0.0 {
+        return Err(InputError {
+            message: "the fundamental frequency must be positive",
+        });
+    }
+    let mut harmonics: Vec<(f32, Note, f32)> = Vec::new();
+    for harmonic in 1..=count {
+        let frequency = fundamental_hz * harmonic as f32;
+        let (note, cents) = Note::from_frequency(frequency, 440.0)?;
+        harmonics.push((frequency, note, cents));
+    }
+    Ok(harmonics)
+}
+
+/// Returns a vector with the spelled names of every note of a chromatic run between two notes,
+/// including both notes, which can be used to write out a glissando. The run descends if the
+/// second note is lower than the first. Every note that does not correspond to a natural note is
+/// spelled with a flat or a sharp depending on the preference given, so the spelling of the run is
+/// consistent, and the octave of each note is taken from its position rather than its spelling.
+///
+/// # Parameters
+///
+/// - `from`: The [`Note`] where the run starts.
+/// - `to`: The [`Note`] where the run ends.
+/// - `prefer_flats`: A boolean which if set to true spells the notes of the run with flats, or if
+///   set to false spells them with sharps.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::note::{chromatic_run, Note};
+/// use std::str::FromStr;
+///
+/// let from = Note::from_str("E4").unwrap();
+/// let to = Note::from_str("C4").unwrap();
+/// assert_eq!(chromatic_run(from, to, true), vec!["E4", "E♭4", "D4", "D♭4", "C4"]);
+/// ```
+pub fn chromatic_run(from: Note, to: Note, prefer_flats: bool) -> Vec<String> {
+    let distance = to.get_value() - from.get_value();
+    let step = if distance < 0 { -1 } else { 1 };
+    (0..=distance.abs() as isize)
+        .map(|index| from.at_offset(step * index).get_spelled_name(prefer_flats))
+        .collect()
+}
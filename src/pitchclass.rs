@@ -40,6 +40,26 @@ impl PitchClass {
             message: "the value provided must be an integer between 0 and 11",
         })
     }
+
+    /// Returns an [`Option<PitchClass>`] which contains the pitch class with a given value from 0
+    /// to 11, where 0 represents C, 1 represents C sharp and so on, or [`None`] if the value is
+    /// greater than 11.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: An integer from 0 to 11 representing the [`PitchClass`] to return.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// assert_eq!(Some(PitchClass::E), PitchClass::from_value(4));
+    /// assert_eq!(None, PitchClass::from_value(12));
+    /// ```
+    pub fn from_value(value: u8) -> Option<PitchClass> {
+        Self::from_chromatic_index(value as usize)
+    }
 }
 
 impl PitchClass {
@@ -72,11 +92,56 @@ impl PitchClass {
         self.reference.value
     }
 
+    /// Returns the chromatic index of the pitch class from 0 to 11, where 0 corresponds to C, 1 to
+    /// C sharp and so on. This is the same as the value of the pitch class but as a [`usize`] so
+    /// that it can be used to index arrays of pitch classes.
+    pub fn chromatic_index(&self) -> usize {
+        self.reference.value as usize
+    }
+
+    /// Returns an [`Option<PitchClass>`] which contains the pitch class with a given chromatic
+    /// index from 0 to 11, or [`None`] if the index is greater than 11.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: An integer from 0 to 11 representing the chromatic index of the pitch class.
+    pub fn from_chromatic_index(index: usize) -> Option<PitchClass> {
+        PITCH_CLASSES
+            .get(index)
+            .map(|reference| PitchClass { reference })
+    }
+
     /// Returns a vector of equivalent names for this pitch class.
     pub fn get_names(&self) -> &'static [&'static str] {
         self.reference.names
     }
 
+    /// Returns the most common name of the pitch class, using a flat or a sharp for pitch classes
+    /// that do not correspond to a natural note depending on the preference given.
+    ///
+    /// # Parameters
+    ///
+    /// - `prefer_flats`: A boolean which if set to true spells pitch classes such as A sharp as B♭,
+    ///   or if set to false spells them as A♯.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::pitchclass::PitchClass;
+    ///
+    /// assert_eq!(PitchClass::A_SHARP.get_spelled_name(true), "B♭");
+    /// assert_eq!(PitchClass::B_FLAT.get_spelled_name(false), "A♯");
+    /// assert_eq!(PitchClass::C.get_spelled_name(true), "C");
+    /// ```
+    pub fn get_spelled_name(&self, prefer_flats: bool) -> &'static str {
+        let names = self.reference.names;
+        if prefer_flats && names[0].contains('♯') {
+            names[2]
+        } else {
+            names[0]
+        }
+    }
+
     // Real pitch classes
 
     /// The pitch class for C.
@@ -174,10 +239,6 @@ struct StaticPitchClass {
     names: &'static [&'static str],
 }
 
-trait TryFromStaticPitchClass {
-    fn try_from(value: impl Into<u64>) -> Result<&'static Self, InputError>;
-}
-
 impl Default for PitchClass {
     fn default() -> Self {
         PitchClass::C
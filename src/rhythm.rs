@@ -0,0 +1,365 @@
+use crate::common::{gcd, Beat, Fraction, InputError};
+
+/// A structure which holds a rhythm, which is a sequence of beats played at a given tempo and with
+/// a given time signature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rhythm {
+    tempo: f32,
+    time_signature: Fraction,
+    beats: Vec<Beat>,
+    pickup: Beat,
+}
+
+impl Rhythm {
+    /// Creates a rhythm with a given tempo, time signature and sequence of beats.
+    ///
+    /// # Parameters
+    ///
+    /// - `tempo`: The tempo of the rhythm in beats per minute.
+    /// - `time_signature`: A [`Fraction`] representing the time signature of the rhythm.
+    /// - `beats`: A slice of [`Beat`] representing the duration of each beat of the rhythm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::HALF, Beat::HALF]);
+    /// assert_eq!(rhythm.get_beats().len(), 2);
+    /// ```
+    pub fn new(tempo: f32, time_signature: Fraction, beats: &[Beat]) -> Self {
+        Self {
+            tempo,
+            time_signature,
+            beats: beats.to_vec(),
+            pickup: Beat::new(0, 1),
+        }
+    }
+
+    /// Creates a rhythm with a given tempo and time signature from a string with a compact
+    /// notation of the beats, where each beat is separated by whitespace. Each beat is written as
+    /// `w` for a whole note, `h` for a half note, `q` for a quarter note, `e` for an eighth note,
+    /// `s` for a sixteenth note or `t` for a thirty-second note, optionally followed by a `.` to
+    /// make it dotted. This function returns a [`Result`] which can contain the [`Rhythm`] or an
+    /// [`InputError`] if any of the beats could not be parsed.
+    ///
+    /// # Parameters
+    ///
+    /// - `tempo`: The tempo of the rhythm in beats per minute.
+    /// - `time_signature`: A [`Fraction`] representing the time signature of the rhythm.
+    /// - `pattern`: A string with the beats of the rhythm written in the compact notation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::from_pattern(120.0, Fraction::new(4, 4), "q. e h").unwrap();
+    /// assert_eq!(
+    ///     rhythm.get_beats(),
+    ///     vec![Beat::QUARTER_DOTTED, Beat::EIGHTH, Beat::HALF]
+    /// );
+    /// ```
+    pub fn from_pattern(
+        tempo: f32,
+        time_signature: Fraction,
+        pattern: &str,
+    ) -> Result<Self, InputError> {
+        let mut beats: Vec<Beat> = Vec::new();
+        for token in pattern.split_whitespace() {
+            let beat = match token {
+                "w" => Beat::WHOLE,
+                "h" => Beat::HALF,
+                "q" => Beat::QUARTER,
+                "e" => Beat::EIGHTH,
+                "s" => Beat::SIXTEENTH,
+                "t" => Beat::THIRTYSECOND,
+                "w." => Beat::WHOLE_DOTTED,
+                "h." => Beat::HALF_DOTTED,
+                "q." => Beat::QUARTER_DOTTED,
+                "e." => Beat::EIGHTH_DOTTED,
+                "s." => Beat::SIXTEENTH_DOTTED,
+                "t." => Beat::THIRTYSECOND_DOTTED,
+                _ => {
+                    return Err(InputError {
+                        message: "the pattern contains a beat that could not be parsed",
+                    })
+                }
+            };
+            beats.push(beat);
+        }
+        Ok(Self::new(tempo, time_signature, &beats))
+    }
+
+    /// Adds a beat to the end of the rhythm.
+    ///
+    /// # Parameters
+    ///
+    /// - `beat`: A [`Beat`] representing the duration of the beat to add.
+    pub fn add_beat(&mut self, beat: Beat) {
+        self.beats.push(beat);
+    }
+
+    /// Adds all the beats of another rhythm to the end of the current rhythm. The tempo and time
+    /// signature of the other rhythm are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A reference to the [`Rhythm`] whose beats will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let mut rhythm = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::HALF]);
+    /// let other = Rhythm::new(90.0, Fraction::new(3, 4), &[Beat::QUARTER, Beat::QUARTER]);
+    /// rhythm.append(&other);
+    /// assert_eq!(rhythm.get_beats(), vec![Beat::HALF, Beat::QUARTER, Beat::QUARTER]);
+    /// assert_eq!(rhythm.get_tempo(), 120.0);
+    /// ```
+    pub fn append(&mut self, other: &Rhythm) {
+        self.beats.extend_from_slice(&other.beats);
+    }
+
+    /// Returns a new rhythm with the same tempo and time signature as the current rhythm, where
+    /// the beats of the current rhythm are repeated a given number of times.
+    ///
+    /// # Parameters
+    ///
+    /// - `times`: The number of times to repeat the beats of the rhythm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::HALF, Beat::QUARTER]);
+    /// assert_eq!(rhythm.repeat(3).get_beats().len(), 6);
+    /// ```
+    pub fn repeat(&self, times: usize) -> Rhythm {
+        Self {
+            tempo: self.tempo,
+            time_signature: self.time_signature,
+            beats: self.beats.repeat(times),
+            pickup: self.pickup,
+        }
+    }
+
+    /// Sets the tempo of the rhythm to a given value in beats per minute.
+    ///
+    /// # Parameters
+    ///
+    /// - `tempo`: The new tempo of the rhythm.
+    pub fn set_tempo(&mut self, tempo: f32) {
+        self.tempo = tempo;
+    }
+
+    /// Sets the time signature of the rhythm to a given value.
+    ///
+    /// # Parameters
+    ///
+    /// - `time_signature`: A [`Fraction`] representing the new time signature of the rhythm.
+    pub fn set_time_signature(&mut self, time_signature: Fraction) {
+        self.time_signature = time_signature;
+    }
+
+    /// Sets the duration of the pickup or anacrusis of the rhythm, which is an incomplete measure
+    /// played before the first full measure. By default rhythms have no pickup.
+    ///
+    /// # Parameters
+    ///
+    /// - `beats`: A [`Beat`] representing the duration of the pickup measure.
+    pub fn set_pickup(&mut self, beats: Beat) {
+        self.pickup = beats;
+    }
+
+    /// Returns a [`Beat`] representing the duration of the pickup measure of the rhythm.
+    pub fn get_pickup(&self) -> Beat {
+        self.pickup
+    }
+
+    /// Returns a vector with the indices of the beats of the rhythm which fall on the start of a
+    /// full measure, taking into account the time signature and the pickup measure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let mut rhythm = Rhythm::from_pattern(120.0, Fraction::new(3, 4), "q h q q q h").unwrap();
+    /// assert_eq!(rhythm.get_measure_starts(), vec![0, 2, 5]);
+    /// rhythm.set_pickup(Beat::QUARTER);
+    /// assert_eq!(rhythm.get_measure_starts(), vec![1, 4]);
+    /// ```
+    pub fn get_measure_starts(&self) -> Vec<usize> {
+        let resolution = self
+            .beats
+            .iter()
+            .chain([&self.pickup, &self.time_signature])
+            .map(|fraction| fraction.get_denominator() as u64)
+            .fold(1, lcm);
+        let to_units = |fraction: &Fraction| {
+            fraction.get_numerator() as u64 * resolution / fraction.get_denominator() as u64
+        };
+        let measure = to_units(&self.time_signature);
+        let pickup = to_units(&self.pickup);
+        if measure == 0 {
+            return Vec::new();
+        }
+        let mut measure_starts: Vec<usize> = Vec::new();
+        let mut position = 0;
+        for (index, beat) in self.beats.iter().enumerate() {
+            if position >= pickup && (position - pickup).is_multiple_of(measure) {
+                measure_starts.push(index);
+            }
+            position += to_units(beat);
+        }
+        measure_starts
+    }
+
+    /// Returns a [`Result`] which can contain a vector of [`Fraction`] with the onset of each beat
+    /// of the rhythm, which is the position where the beat starts measured in whole notes from the
+    /// start of the rhythm, or an [`InputError`] if the simplified numerator or denominator of an
+    /// onset does not fit in a [`u8`]. This can happen for long rhythms, for example after about 32
+    /// measures of eighth notes in 4/4, in which case [`Rhythm::onset_seconds`] can be used
+    /// instead. The onsets are simplified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Fraction;
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::from_pattern(120.0, Fraction::new(4, 4), "h q q").unwrap();
+    /// assert_eq!(
+    ///     rhythm.onsets().unwrap(),
+    ///     vec![Fraction::new(0, 1), Fraction::new(1, 2), Fraction::new(3, 4)]
+    /// );
+    /// ```
+    pub fn onsets(&self) -> Result<Vec<Fraction>, InputError> {
+        let (positions, resolution) = self.onset_positions();
+        positions
+            .iter()
+            .map(|position| {
+                let common_factor = gcd(*position, resolution);
+                match (
+                    u8::try_from(position / common_factor),
+                    u8::try_from(resolution / common_factor),
+                ) {
+                    (Ok(numerator), Ok(denominator)) => Ok(Fraction::new(numerator, denominator)),
+                    _ => Err(InputError {
+                        message: "an onset of the rhythm does not fit in a fraction of u8 values",
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a vector with the onset of each beat of the rhythm in seconds from the start of the
+    /// rhythm, calculated using the tempo of the rhythm in quarter notes per minute. Unlike
+    /// [`Rhythm::onsets`], this works for rhythms of any length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Fraction;
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::from_pattern(120.0, Fraction::new(4, 4), "h q q").unwrap();
+    /// assert_eq!(rhythm.onset_seconds(), vec![0.0, 1.0, 1.5]);
+    /// ```
+    pub fn onset_seconds(&self) -> Vec<f32> {
+        let (positions, resolution) = self.onset_positions();
+        positions
+            .iter()
+            .map(|position| {
+                (240.0 * *position as f64 / resolution as f64 / self.tempo as f64) as f32
+            })
+            .collect()
+    }
+
+    fn onset_positions(&self) -> (Vec<u64>, u64) {
+        let resolution = self
+            .beats
+            .iter()
+            .map(|beat| beat.get_denominator() as u64)
+            .fold(1, lcm);
+        let mut positions: Vec<u64> = Vec::new();
+        let mut position: u64 = 0;
+        for beat in &self.beats {
+            positions.push(position);
+            position += beat.get_numerator() as u64 * resolution / beat.get_denominator() as u64;
+        }
+        (positions, resolution)
+    }
+
+    /// Returns the tempo of the rhythm in beats per minute.
+    pub fn get_tempo(&self) -> f32 {
+        self.tempo
+    }
+
+    /// Returns a [`Fraction`] representing the time signature of the rhythm.
+    pub fn get_time_signature(&self) -> Fraction {
+        self.time_signature
+    }
+
+    /// Returns a vector of [`Beat`] with each of the beats of the rhythm.
+    pub fn get_beats(&self) -> Vec<Beat> {
+        self.beats.clone()
+    }
+
+    /// Returns a string which represents the rhythm on a grid, where each character represents the
+    /// smallest subdivision needed to place every beat. Each beat is drawn as an `x` at its onset
+    /// followed by a `-` for each subdivision that it is held for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::rhythm::Rhythm;
+    ///
+    /// let rhythm = Rhythm::new(
+    ///     120.0,
+    ///     Fraction::new(4, 4),
+    ///     &[Beat::QUARTER_DOTTED, Beat::EIGHTH, Beat::HALF],
+    /// );
+    /// assert_eq!(rhythm.to_ascii(), "x--xx---");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        let resolution = self
+            .beats
+            .iter()
+            .map(|beat| beat.get_simplified().get_denominator() as u64)
+            .fold(1, lcm);
+        let mut grid = String::new();
+        for beat in &self.beats {
+            let cells = beat.get_numerator() as u64 * resolution / beat.get_denominator() as u64;
+            if cells > 0 {
+                grid.push('x');
+                grid.push_str(&"-".repeat(cells as usize - 1));
+            }
+        }
+        grid
+    }
+}
+
+impl Default for Rhythm {
+    fn default() -> Self {
+        Self {
+            tempo: 120.0,
+            time_signature: Fraction::new(4, 4),
+            beats: Vec::new(),
+            pickup: Beat::new(0, 1),
+        }
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
@@ -3,6 +3,7 @@ use crate::common::{InputError, PentatonicType, ScaleType};
 use crate::interval::Interval;
 use crate::note::Note;
 use crate::pitchclass::PitchClass;
+use std::str::FromStr;
 
 /// A structure used to represent a scale of notes, or a major or minor pentatonic variation of a
 /// scale.
@@ -11,12 +12,13 @@ pub struct Scale {
     intervals: Vec<Interval>,
     scale: ScaleType,
     pentatonic: PentatonicType,
+    name: String,
 }
 
 impl Scale {
     /// Constructs a scale of notes given the type of scale, and optionally a pentatonic. This
     /// function returns a [`Result`] which can contain the [`Scale`] or an [`InputError`] if the
-    /// input parameters were invalid.
+    /// input parameters were invalid, including if the type of scale is [`ScaleType::Custom`].
     ///
     /// # Parameters
     ///
@@ -70,7 +72,15 @@ impl Scale {
             26 => vec![0, 3, 5, 6, 7, 10, 12],
             27 => vec![0, 2, 4, 6, 8, 10, 12],
             28 => vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
-            _ => unimplemented!(),
+            29 => vec![0, 3, 4, 7, 8, 11, 12],
+            30 => vec![0, 2, 4, 5, 7, 9, 10, 11, 12],
+            31 => vec![0, 2, 4, 5, 7, 8, 9, 11, 12],
+            32 => vec![0, 2, 3, 6, 7, 8, 11, 12],
+            _ => {
+                return Err(InputError {
+                    message: "custom scales must be constructed from their steps",
+                })
+            }
         };
         let mut intervals: Vec<Interval> = scale_intervals
             .iter()
@@ -88,10 +98,84 @@ impl Scale {
             intervals.remove(5);
             intervals.remove(1);
         }
+        let name = if pentatonic == PentatonicType::None {
+            format!("{scale:?}")
+        } else {
+            format!("{scale:?} ({pentatonic:?} Pentatonic)")
+        };
         Ok(Self {
             intervals,
             scale,
             pentatonic,
+            name,
+        })
+    }
+
+    /// Constructs a scale from a pattern of steps, where each step is the number of semitones
+    /// between two consecutive notes of the scale, such as 2 for a whole step and 1 for a half
+    /// step. This function returns a [`Result`] which can contain the [`Scale`] with the given
+    /// name, or an [`InputError`] if the steps do not add up to an octave or if any of the steps
+    /// is zero. If the steps correspond to one of the scales or pentatonics of [`ScaleType`] then
+    /// the scale has that type, otherwise its type is [`ScaleType::Custom`].
+    ///
+    /// # Parameters
+    ///
+    /// - `steps`: A slice of integers representing the number of semitones of each step.
+    /// - `name`: A string representing the name of the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let major = Scale::from_steps(&[2, 2, 1, 2, 2, 2, 1], "Major").unwrap();
+    /// assert_eq!(major, Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap());
+    /// let hirajoshi = Scale::from_steps(&[2, 1, 4, 1, 4], "Hirajoshi").unwrap();
+    /// assert_eq!(hirajoshi.get_scale_type(), ScaleType::Custom);
+    /// assert_eq!(hirajoshi.get_name(), "Hirajoshi");
+    /// ```
+    pub fn from_steps(steps: &[usize], name: &str) -> Result<Self, InputError> {
+        if steps.iter().sum::<usize>() != 12 {
+            return Err(InputError {
+                message: "the steps of the scale must add up to an octave",
+            });
+        }
+        if steps.contains(&0) {
+            return Err(InputError {
+                message: "the steps of the scale cannot be zero",
+            });
+        }
+        let mut semitones: Vec<u64> = vec![0];
+        for step in steps {
+            semitones.push(semitones[semitones.len() - 1] + *step as u64);
+        }
+        for scale in SCALE_TYPES {
+            for pentatonic in [
+                PentatonicType::None,
+                PentatonicType::Major,
+                PentatonicType::Minor,
+            ] {
+                if let Ok(candidate) = Self::try_new(scale, pentatonic) {
+                    let candidate_semitones: Vec<u64> = candidate
+                        .intervals
+                        .iter()
+                        .map(|interval| interval.get_value())
+                        .collect();
+                    if candidate_semitones == semitones {
+                        return Ok(candidate.with_name(name));
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            intervals: semitones
+                .iter()
+                .map(|value| Interval::from(*value))
+                .collect(),
+            scale: ScaleType::Custom,
+            pentatonic: PentatonicType::None,
+            name: name.to_string(),
         })
     }
 
@@ -106,6 +190,49 @@ impl Scale {
         self.pentatonic
     }
 
+    /// Returns the name of the scale. By default this is the name of the type of the scale,
+    /// followed by the type of the pentatonic in parentheses if the scale is a pentatonic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// assert_eq!(major.get_name(), "Major");
+    /// let pentatonic = Scale::try_new(ScaleType::Minor, PentatonicType::Minor).unwrap();
+    /// assert_eq!(pentatonic.get_name(), "Minor (Minor Pentatonic)");
+    /// ```
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns a copy of the current scale with a different name, which does not change the notes
+    /// of the scale.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: A string representing the new name of the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let scale = Scale::try_new(ScaleType::Major, PentatonicType::Major)
+    ///     .unwrap()
+    ///     .with_name("Gong");
+    /// assert_eq!(scale.get_name(), "Gong");
+    /// ```
+    pub fn with_name(self, name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..self
+        }
+    }
+
     /// Returns true if the scale is diatonic or heptatonic (has 7 notes), or false if otherwise.
     pub fn is_diatonic(&self) -> bool {
         self.intervals.len() == 8
@@ -116,6 +243,34 @@ impl Scale {
         self.intervals.len() == 6
     }
 
+    /// Returns an [`Option<usize>`] with the smallest amount of semitones that the notes of the
+    /// scale can be transposed by to obtain the same notes, if the scale is symmetric and repeats
+    /// its pattern of intervals within an octave, or [`None`] if it is not symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let whole = Scale::try_new(ScaleType::Whole, PentatonicType::None).unwrap();
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// assert_eq!(whole.is_symmetric(), Some(2));
+    /// assert_eq!(major.is_symmetric(), None);
+    /// ```
+    pub fn is_symmetric(&self) -> Option<usize> {
+        let semitones: Vec<u64> = self
+            .intervals
+            .iter()
+            .map(|interval| interval.get_value() % 12)
+            .collect();
+        (1..12).find(|period| {
+            semitones
+                .iter()
+                .all(|semitone| semitones.contains(&((semitone + *period as u64) % 12)))
+        })
+    }
+
     /// Returns a [`Result`] which can contain a [`Vec<Chord>`] consisting of the seven diatonic
     /// chords of the current scale, given the pitch class of the tonic and optionally the octave of
     /// each of these chords, or an [`InputError`] if the current scale is not diatonic.
@@ -128,12 +283,12 @@ impl Scale {
     ///   diatonic chord, or [`None`] if the chords should not have any octave.
     /// - `with_seventh`: A boolean which if set to true ensures that the chords that are returned
     ///   contain the corresponding seventh intervals for the mode or scale, or if set to false
-    ///   ensures that the chords that are returns are only triads.
+    ///   ensures that the chords that are returned are only triads.
     ///
     /// # Examples
     ///
-    /// The following example shows how one can obtain the diatonic chords with sevenths for the G
-    /// locrian scale, starting at the fifth octave.
+    /// The following example shows how one can obtain the diatonic triads for the G locrian scale,
+    /// starting at the fifth octave.
     ///
     /// ```rust
     /// use music_tools::scale::Scale;
@@ -218,6 +373,194 @@ impl Scale {
         Ok(chords)
     }
 
+    /// Returns a [`Result`] which can contain a vector with the roman numeral of the triad built on
+    /// each degree of the current scale, or an [`InputError`] if the scale is not diatonic or if
+    /// any of its triads is not a major, minor, diminished or augmented triad. The numerals are
+    /// written in the same format as [`Chord::from_numeral`], where degrees which are lower or
+    /// higher than the corresponding degree of the major scale are prefixed with `b` or `#`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let dorian = Scale::try_new(ScaleType::Dorian, PentatonicType::None).unwrap();
+    /// assert_eq!(
+    ///     dorian.degree_numerals().unwrap(),
+    ///     vec!["i", "ii", "bIII", "IV", "v", "vi°", "bVII"]
+    /// );
+    /// ```
+    pub fn degree_numerals(&self) -> Result<Vec<String>, InputError> {
+        if !self.is_diatonic() {
+            return Err(InputError {
+                message: "cannot obtain the numerals of a scale that is not diatonic",
+            });
+        }
+        let numerals = ["I", "II", "III", "IV", "V", "VI", "VII"];
+        let major_values = [0, 2, 4, 5, 7, 9, 11];
+        let values: Vec<u64> = self.intervals[..7]
+            .iter()
+            .map(|interval| interval.get_value())
+            .collect();
+        let mut degree_numerals: Vec<String> = Vec::new();
+        for degree in 0..7 {
+            let third = (values[(degree + 2) % 7] + 12 - values[degree]) % 12;
+            let fifth = (values[(degree + 4) % 7] + 12 - values[degree]) % 12;
+            let numeral = match (third, fifth) {
+                (4, 7) => numerals[degree].to_string(),
+                (3, 7) => numerals[degree].to_lowercase(),
+                (3, 6) => format!("{}°", numerals[degree].to_lowercase()),
+                (4, 8) => format!("{}+", numerals[degree]),
+                _ => {
+                    return Err(InputError {
+                        message: "the scale contains a triad which cannot be written as a numeral",
+                    })
+                }
+            };
+            let accidental = match values[degree] as i64 - major_values[degree] {
+                -1 => "b",
+                0 => "",
+                1 => "#",
+                _ => {
+                    return Err(InputError {
+                        message: "the scale contains a degree which cannot be written as a numeral",
+                    })
+                }
+            };
+            degree_numerals.push(format!("{accidental}{numeral}"));
+        }
+        Ok(degree_numerals)
+    }
+
+    /// Returns the brightness of the current scale, which is the sum of the amount of semitones
+    /// that each degree of the scale is above or below the same degree of the major scale. Brighter
+    /// scales have raised degrees and a higher brightness, so the modes of the major scale range
+    /// from Lydian with a brightness of 1 to Locrian with a brightness of -5. The metric is meant
+    /// for heptatonic scales, and scales with a different amount of notes are compared degree by
+    /// degree with the major scale extended over as many octaves as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let dorian = Scale::try_new(ScaleType::Dorian, PentatonicType::None).unwrap();
+    /// let phrygian = Scale::try_new(ScaleType::Phrygian, PentatonicType::None).unwrap();
+    /// assert_eq!(dorian.brightness(), -2);
+    /// assert!(dorian.brightness() > phrygian.brightness());
+    /// ```
+    pub fn brightness(&self) -> isize {
+        let major_values = [0, 2, 4, 5, 7, 9, 11];
+        self.intervals[..self.intervals.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(degree, interval)| {
+                let major_value = major_values[degree % 7] + 12 * (degree / 7) as isize;
+                interval.get_value() as isize - major_value
+            })
+            .sum()
+    }
+
+    /// Returns a [`Result`] which can contain the tonic triad of the current scale, which is the
+    /// diatonic chord built on the first degree (I), given the pitch class of the tonic and
+    /// optionally the octave of the first diatonic chord, or an [`InputError`] if the current scale
+    /// is not diatonic.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A [`PitchClass`] representing the pitch class of the tonic of the scale.
+    /// - `octave`: An [`Option<i8>`] which can be an integer representing the octave of the first
+    ///   diatonic chord, or [`None`] if the chord should not have any octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::chord::Chord;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use music_tools::common::{ScaleType, PentatonicType, TriadQuality};
+    ///
+    /// let minor = Scale::try_new(ScaleType::Minor, PentatonicType::None).unwrap();
+    /// assert_eq!(
+    ///     minor.tonic_chord(PitchClass::A, Some(3)).unwrap(),
+    ///     Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3))
+    /// );
+    /// ```
+    pub fn tonic_chord(&self, tonic: PitchClass, octave: Option<i8>) -> Result<Chord, InputError> {
+        let chords = self.get_diatonic_chords(tonic, octave, false)?;
+        Ok(chords[0].clone())
+    }
+
+    /// Returns a [`Result`] which can contain the subdominant triad of the current scale, which is
+    /// the diatonic chord built on the fourth degree (IV), given the pitch class of the tonic
+    /// and optionally the octave of the first diatonic chord, or an [`InputError`] if the
+    /// current scale is not diatonic.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A [`PitchClass`] representing the pitch class of the tonic of the scale.
+    /// - `octave`: An [`Option<i8>`] which can be an integer representing the octave of the first
+    ///   diatonic chord, or [`None`] if the chord should not have any octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::chord::Chord;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use music_tools::common::{ScaleType, PentatonicType, TriadQuality};
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// assert_eq!(
+    ///     major.subdominant_chord(PitchClass::C, Some(4)).unwrap(),
+    ///     Chord::from_triad(TriadQuality::Major, Some(PitchClass::F), Some(4))
+    /// );
+    /// ```
+    pub fn subdominant_chord(
+        &self,
+        tonic: PitchClass,
+        octave: Option<i8>,
+    ) -> Result<Chord, InputError> {
+        let chords = self.get_diatonic_chords(tonic, octave, false)?;
+        Ok(chords[3].clone())
+    }
+
+    /// Returns a [`Result`] which can contain the dominant triad of the current scale, which is the
+    /// diatonic chord built on the fifth degree (V), given the pitch class of the tonic and
+    /// optionally the octave of the first diatonic chord, or an [`InputError`] if the current scale
+    /// is not diatonic.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A [`PitchClass`] representing the pitch class of the tonic of the scale.
+    /// - `octave`: An [`Option<i8>`] which can be an integer representing the octave of the first
+    ///   diatonic chord, or [`None`] if the chord should not have any octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::chord::Chord;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use music_tools::common::{ScaleType, PentatonicType, TriadQuality};
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// assert_eq!(
+    ///     major.dominant_chord(PitchClass::C, Some(4)).unwrap(),
+    ///     Chord::from_triad(TriadQuality::Major, Some(PitchClass::G), Some(4))
+    /// );
+    /// ```
+    pub fn dominant_chord(
+        &self,
+        tonic: PitchClass,
+        octave: Option<i8>,
+    ) -> Result<Chord, InputError> {
+        let chords = self.get_diatonic_chords(tonic, octave, false)?;
+        Ok(chords[4].clone())
+    }
+
     /// Returns a vector with each of the intervals of the scale.
     pub fn get_intervals(&self) -> Vec<Interval> {
         self.intervals.clone()
@@ -237,6 +580,41 @@ impl Scale {
         Vec::<Note>::try_from(chord).unwrap()
     }
 
+    /// Returns an iterator of [`Note`] which ascends through the scale starting at a given note,
+    /// climbing to the next octave every time the tonic is passed. The iterator only ends once the
+    /// next note would be above octave 127, which is the highest octave a [`Note`] can hold.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: A [`Note`] representing the tonic of the scale and the first note returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::note::Note;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    /// use std::str::FromStr;
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// let ninth = major.note_iter(Note::from_str("C4").unwrap()).nth(8).unwrap();
+    /// assert_eq!(Note::from_str("D5").unwrap(), ninth);
+    /// ```
+    pub fn note_iter(&self, start: Note) -> impl Iterator<Item = Note> {
+        let steps: Vec<u64> = self.intervals[..self.intervals.len() - 1]
+            .iter()
+            .map(|interval| interval.get_value())
+            .collect();
+        (0..).map_while(move |index: usize| {
+            let octave_offset = (index / steps.len()) as isize * 12;
+            let offset = octave_offset + steps[index % steps.len()] as isize;
+            if (start.get_value() as isize + offset).div_euclid(12) > i8::MAX as isize {
+                return None;
+            }
+            Some(start.at_offset(offset))
+        })
+    }
+
     /// Converts the scale to a vector of [`PitchClass`], given a pitch class as the tonic.
     ///
     /// # Parameters
@@ -248,6 +626,173 @@ impl Scale {
         chord.set_tonic(Some(tonic));
         Vec::try_from(chord).unwrap()
     }
+
+    /// Returns a [`Result`] which can contain a vector with the correctly spelled name of each
+    /// note of a heptatonic scale starting from a given tonic, or an [`InputError`] if the scale is
+    /// not heptatonic, the tonic is not valid or a note cannot be spelled with at most two
+    /// accidentals. Each note of the scale uses the next letter after the letter of the previous
+    /// note, so for example the D major scale is spelled with F♯ and C♯ rather than G♭ and D♭.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A string with the name of the tonic of the scale, such as `"D"`, `"Bb"` or
+    ///   `"F♯"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// let names = major.get_spelled_names("F").unwrap();
+    /// assert_eq!(names, vec!["F", "G", "A", "B♭", "C", "D", "E"]);
+    /// ```
+    pub fn get_spelled_names(&self, tonic: &str) -> Result<Vec<String>, InputError> {
+        if !self.is_diatonic() {
+            return Err(InputError {
+                message: "cannot spell the notes of a scale that is not heptatonic",
+            });
+        }
+        let letters = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+        let natural_values = [0, 2, 4, 5, 7, 9, 11];
+        let invalid_tonic_error = InputError {
+            message: "the tonic must be a letter from A to G optionally followed by accidentals",
+        };
+        let tonic_pitch_class = PitchClass::from_str(tonic).map_err(|_| invalid_tonic_error)?;
+        let tonic_letter = tonic
+            .chars()
+            .next()
+            .and_then(|letter| letters.iter().position(|&x| x == letter))
+            .ok_or(invalid_tonic_error)?;
+        let mut names = Vec::new();
+        for (degree, interval) in self.intervals[..7].iter().enumerate() {
+            let letter = (tonic_letter + degree) % 7;
+            let value = tonic_pitch_class.get_offset((interval.get_value() % 12) as i8);
+            let difference = (value.get_value() as i8 - natural_values[letter] + 18) % 12 - 6;
+            let accidental = match difference {
+                -2 => "♭♭",
+                -1 => "♭",
+                0 => "",
+                1 => "♯",
+                2 => "♯♯",
+                _ => {
+                    return Err(InputError {
+                        message: "the notes of the scale cannot be spelled from the given tonic",
+                    })
+                }
+            };
+            names.push(format!("{}{}", letters[letter], accidental));
+        }
+        Ok(names)
+    }
+
+    /// Returns the [`Note`] of the scale which is closest to a given note, given a pitch class as
+    /// the tonic of the scale. If the note is equally close to two notes of the scale then the
+    /// lower one is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A [`PitchClass`] representing the pitch class of the tonic of the scale.
+    /// - `note`: The [`Note`] to snap to the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::note::Note;
+    /// use music_tools::pitchclass::PitchClass;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    /// use std::str::FromStr;
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// let snapped = major.snap(&PitchClass::C, &Note::from_str("F#4").unwrap());
+    /// assert_eq!(snapped, Note::from_str("F4").unwrap());
+    /// ```
+    pub fn snap(&self, tonic: &PitchClass, note: &Note) -> Note {
+        let semitones: Vec<u64> = self
+            .intervals
+            .iter()
+            .map(|interval| interval.get_value() % 12)
+            .collect();
+        let in_scale = |candidate: &Note| {
+            let semitone = (candidate.get_pitch_class().get_value() as i16
+                - tonic.get_value() as i16)
+                .rem_euclid(12) as u64;
+            semitones.contains(&semitone)
+        };
+        for distance in 0..12 {
+            for offset in [-distance, distance] {
+                let candidate = note.at_offset(offset);
+                if in_scale(&candidate) {
+                    return candidate;
+                }
+            }
+        }
+        *note
+    }
+
+    /// Returns true if every pitch class of a chord belongs to the current scale, given a pitch
+    /// class as the tonic of the scale. Chords that do not have a tonic are never contained in the
+    /// scale.
+    ///
+    /// # Parameters
+    ///
+    /// - `tonic`: A reference to a [`PitchClass`] representing the tonic of the scale.
+    /// - `chord`: A reference to the [`Chord`] to check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::chord::Chord;
+    /// use music_tools::common::{PentatonicType, ScaleType, TriadQuality};
+    /// use music_tools::pitchclass::PitchClass;
+    /// use music_tools::scale::Scale;
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// let d_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::D), None);
+    /// assert!(major.contains_chord(&PitchClass::C, &d_minor));
+    /// ```
+    pub fn contains_chord(&self, tonic: &PitchClass, chord: &Chord) -> bool {
+        let scale_pitch_classes = self.to_pitch_classes(*tonic);
+        match Vec::<PitchClass>::try_from(chord.clone()) {
+            Ok(pitch_classes) => pitch_classes
+                .iter()
+                .all(|pitch_class| scale_pitch_classes.contains(pitch_class)),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns a [`Result`] which can contain a tuple with the semitones of the lower and upper
+    /// tetrachords of the current scale, or an [`InputError`] if the current scale is not diatonic.
+    /// The lower tetrachord contains the first four notes of the scale and the upper tetrachord
+    /// contains the last four notes of the scale including the octave, both measured in semitones
+    /// from the tonic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::scale::Scale;
+    /// use music_tools::common::{ScaleType, PentatonicType};
+    ///
+    /// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    /// let (lower, upper) = major.tetrachords().unwrap();
+    /// assert_eq!(lower, vec![0, 2, 4, 5]);
+    /// assert_eq!(upper, vec![7, 9, 11, 12]);
+    /// ```
+    pub fn tetrachords(&self) -> Result<(Vec<usize>, Vec<usize>), InputError> {
+        if !self.is_diatonic() {
+            return Err(InputError {
+                message: "cannot obtain the tetrachords of a scale that is not diatonic",
+            });
+        }
+        let semitones: Vec<usize> = self
+            .intervals
+            .iter()
+            .map(|interval| interval.get_value() as usize)
+            .collect();
+        Ok((semitones[..4].to_vec(), semitones[4..].to_vec()))
+    }
 }
 
 impl Default for Scale {
@@ -258,6 +803,137 @@ impl Default for Scale {
 
 impl PartialEq for Scale {
     fn eq(&self, other: &Self) -> bool {
-        self.scale == other.scale && self.pentatonic == other.pentatonic
+        self.scale == other.scale
+            && self.pentatonic == other.pentatonic
+            && self.intervals == other.intervals
     }
 }
+
+/// Returns a vector with each of the candidate scales and tonics which contain all the pitch
+/// classes of a given set of notes. Every candidate scale is tested with each of the twelve pitch
+/// classes as its tonic, and the matches are returned in the order of the candidates and then of
+/// the tonics starting from C.
+///
+/// # Parameters
+///
+/// - `notes`: A slice of [`Note`] whose pitch classes must all belong to the scale.
+/// - `candidates`: A slice of [`Scale`] with the scales to test.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::common::{PentatonicType, ScaleType};
+/// use music_tools::note::Note;
+/// use music_tools::pitchclass::PitchClass;
+/// use music_tools::scale::{scales_containing, Scale};
+/// use std::str::FromStr;
+///
+/// let notes = ["C4", "E4", "F#4", "B4"].map(|name| Note::from_str(name).unwrap());
+/// let lydian = Scale::try_new(ScaleType::Lydian, PentatonicType::None).unwrap();
+/// assert_eq!(scales_containing(&notes, &[lydian.clone()]), vec![(lydian, PitchClass::C)]);
+/// ```
+pub fn scales_containing(notes: &[Note], candidates: &[Scale]) -> Vec<(Scale, PitchClass)> {
+    let mut matches: Vec<(Scale, PitchClass)> = Vec::new();
+    for candidate in candidates {
+        for index in 0..12 {
+            let tonic = PitchClass::from_chromatic_index(index).unwrap();
+            let pitch_classes = candidate.to_pitch_classes(tonic);
+            if notes
+                .iter()
+                .all(|note| pitch_classes.contains(&note.get_pitch_class()))
+            {
+                matches.push((candidate.clone(), tonic));
+            }
+        }
+    }
+    matches
+}
+
+/// Returns an [`Option`] which can contain the candidate scale and tonic that contain the most
+/// chord tones of a chord progression, or [`None`] if there are no candidates. Every candidate
+/// scale is tested with each of the twelve pitch classes as its tonic, and each pitch class of each
+/// chord that belongs to the scale adds one to its score. If several scales have the same score,
+/// the first one in the order of the candidates and then of the tonics starting from C is
+/// returned. Chords without a tonic do not contribute to the score.
+///
+/// # Parameters
+///
+/// - `chords`: A slice of [`Chord`] containing the chord progression.
+/// - `candidates`: A slice of [`Scale`] with the scales to test.
+///
+/// # Examples
+///
+/// ```rust
+/// use music_tools::chord::Chord;
+/// use music_tools::common::{PentatonicType, ScaleType};
+/// use music_tools::pitchclass::PitchClass;
+/// use music_tools::scale::{best_scale_for_progression, Scale};
+///
+/// let chords = ["ii", "V7", "I"]
+///     .map(|numeral| Chord::from_numeral(numeral, PitchClass::F, None).unwrap());
+/// let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+/// let best = best_scale_for_progression(&chords, &[major.clone()]);
+/// assert_eq!(best, Some((major, PitchClass::F)));
+/// ```
+pub fn best_scale_for_progression(
+    chords: &[Chord],
+    candidates: &[Scale],
+) -> Option<(Scale, PitchClass)> {
+    let chord_pitch_classes: Vec<PitchClass> = chords
+        .iter()
+        .filter_map(|chord| Vec::<PitchClass>::try_from(chord.clone()).ok())
+        .flatten()
+        .collect();
+    let mut best: Option<(Scale, PitchClass)> = None;
+    let mut best_score = 0;
+    for candidate in candidates {
+        for index in 0..12 {
+            let tonic = PitchClass::from_chromatic_index(index).unwrap();
+            let pitch_classes = candidate.to_pitch_classes(tonic);
+            let score = chord_pitch_classes
+                .iter()
+                .filter(|pitch_class| pitch_classes.contains(pitch_class))
+                .count();
+            if best.is_none() || score > best_score {
+                best = Some((candidate.clone(), tonic));
+                best_score = score;
+            }
+        }
+    }
+    best
+}
+
+const SCALE_TYPES: [ScaleType; 32] = [
+    ScaleType::Major,
+    ScaleType::Dorian,
+    ScaleType::Phrygian,
+    ScaleType::Lydian,
+    ScaleType::Mixolydian,
+    ScaleType::Minor,
+    ScaleType::Locrian,
+    ScaleType::HarmonicMinor,
+    ScaleType::LocrianNaturalSix,
+    ScaleType::IonianSharpFive,
+    ScaleType::DorianSharpFour,
+    ScaleType::PhrygianDominant,
+    ScaleType::LydianSharpTwo,
+    ScaleType::AlteredDiminished,
+    ScaleType::MelodicMinor,
+    ScaleType::DorianFlatTwo,
+    ScaleType::LydianAugmented,
+    ScaleType::LydianDominant,
+    ScaleType::MixolydianFlatSix,
+    ScaleType::LocrianSharpTwo,
+    ScaleType::Altered,
+    ScaleType::Diminished,
+    ScaleType::DominantDiminished,
+    ScaleType::NonatonicBlues,
+    ScaleType::MajorBlues,
+    ScaleType::MinorBlues,
+    ScaleType::Whole,
+    ScaleType::Chromatic,
+    ScaleType::Augmented,
+    ScaleType::BebopDominant,
+    ScaleType::BebopMajor,
+    ScaleType::HungarianMinor,
+];
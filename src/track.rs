@@ -1,6 +1,10 @@
 use crate::chord::Chord;
-use crate::common::{Beat, Fraction, IncompleteChordError};
+use crate::common::{gcd, Beat, Fraction, IncompleteChordError, XorShiftRng};
 use crate::note::Note;
+use crate::rhythm::Rhythm;
+use std::collections::HashMap;
+
+const DEFAULT_VELOCITY: u8 = 100;
 
 /// This structure is used to store a track with a sequence of events with the same structure as a
 /// MIDI event, however holding [`Note`] structures instead.
@@ -13,6 +17,8 @@ pub struct Track {
     current_delta_ticks: u64,
     current_event: usize,
     events: Vec<Event>,
+    program: Option<u8>,
+    pickup: u64,
 }
 
 impl Track {
@@ -27,6 +33,8 @@ impl Track {
             current_delta_ticks: 0,
             current_event: 0,
             events: Vec::new(),
+            program: None,
+            pickup: 0,
         }
     }
 
@@ -44,6 +52,8 @@ impl Track {
             current_delta_ticks: 0,
             current_event: 0,
             events: Vec::new(),
+            program: None,
+            pickup: 0,
         }
     }
 
@@ -56,10 +66,31 @@ impl Track {
     /// - `active`: A boolean representing whether to activate or deactivate the note.
     /// - `delta_ticks`: The amount of MIDI ticks until the event should occur.
     pub fn add_event(&mut self, note: Note, active: bool, delta_ticks: u64) {
+        let velocity = if active { DEFAULT_VELOCITY } else { 0 };
+        self.add_event_with_velocity(note, active, velocity, delta_ticks);
+    }
+
+    /// Adds a new [`Event`] with a specific velocity to the current track, which can be used to
+    /// turn a [`Note`] on or off after a certain amount of MIDI ticks.
+    ///
+    /// # Parameters
+    ///
+    /// - `note`: The [`Note`] to be activated or deactivated.
+    /// - `active`: A boolean representing whether to activate or deactivate the note.
+    /// - `velocity`: A [`u8`] between 0 and 127 representing how strongly the note is played.
+    /// - `delta_ticks`: The amount of MIDI ticks until the event should occur.
+    pub fn add_event_with_velocity(
+        &mut self,
+        note: Note,
+        active: bool,
+        velocity: u8,
+        delta_ticks: u64,
+    ) {
         let total_delta_ticks = self.current_delta_ticks + delta_ticks;
         self.events.push(Event {
             note,
             active,
+            velocity: velocity.min(127),
             delta_ticks: total_delta_ticks,
         });
         self.current_delta_ticks = 0;
@@ -78,7 +109,9 @@ impl Track {
         self.add_event(note, false, delta_ticks);
     }
 
-    /// Adds a rest to the end of the current track.
+    /// Adds a rest to the end of the current track. Rests are not stored as events but are added to
+    /// the delta ticks of the next event, so consecutive rests are always merged into a single
+    /// rest.
     ///
     /// # Parameters
     ///
@@ -133,6 +166,85 @@ impl Track {
         self.time_signature = time_signature;
     }
 
+    /// Sets the MIDI program or instrument that the current track should be played with.
+    ///
+    /// # Parameters
+    ///
+    /// - `program`: An [`Option<u8>`] containing the MIDI program number from 0 to 127, or [`None`]
+    ///   if the track has no particular program.
+    pub fn set_program(&mut self, program: Option<u8>) {
+        self.program = program;
+    }
+
+    /// Returns an [`Option<u8>`] containing the MIDI program number of the track, or [`None`] if
+    /// the track has no particular program.
+    pub fn get_program(&self) -> Option<u8> {
+        self.program
+    }
+
+    /// Returns an [`Option<usize>`] containing the index of the synthesizer that should be used to
+    /// play the current track given a map from MIDI programs to synthesizer indices, or [`None`] if
+    /// the track has no program or its program is not in the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `program_map`: A [`HashMap`] from MIDI program numbers to indices of synthesizers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::Fraction;
+    /// use music_tools::track::Track;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.set_program(Some(40));
+    /// let program_map = HashMap::from([(0, 0), (40, 1)]);
+    /// assert_eq!(track.get_synth_index(&program_map), Some(1));
+    /// ```
+    pub fn get_synth_index(&self, program_map: &HashMap<u8, usize>) -> Option<usize> {
+        self.program
+            .and_then(|program| program_map.get(&program).copied())
+    }
+
+    /// Sets the duration of the pickup or anacrusis of the track, which is an incomplete measure
+    /// played before the first full measure. By default tracks have no pickup.
+    ///
+    /// # Parameters
+    ///
+    /// - `duration`: A [`Beat`] representing the duration of the pickup measure.
+    pub fn set_pickup(&mut self, duration: Beat) {
+        self.pickup = self.beat_to_ticks(duration);
+    }
+
+    /// Returns the duration of the pickup measure of the track in MIDI ticks.
+    pub fn get_pickup(&self) -> u64 {
+        self.pickup
+    }
+
+    /// Returns the amount of MIDI ticks from the start of the track until the start of a given full
+    /// measure, taking into account the time signature and the pickup measure.
+    ///
+    /// # Parameters
+    ///
+    /// - `measure`: The index of the full measure, where the first full measure has an index of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::track::Track;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// assert_eq!(track.get_measure_start(1), 1440);
+    /// track.set_pickup(Beat::QUARTER);
+    /// assert_eq!(track.get_measure_start(0), 360);
+    /// assert_eq!(track.get_measure_start(1), 1800);
+    /// ```
+    pub fn get_measure_start(&self, measure: usize) -> u64 {
+        self.pickup + measure as u64 * self.beat_to_ticks(self.time_signature)
+    }
+
     /// Returns the tempo of the track in beats per minute.
     pub fn get_tempo(&self) -> f32 {
         self.tempo
@@ -159,6 +271,11 @@ impl Track {
         None
     }
 
+    /// Returns a vector of [`Event`] with all the events of the track in the order they occur.
+    pub fn get_events(&self) -> Vec<Event> {
+        self.events.clone()
+    }
+
     /// Returns the amount of MIDI ticks in a quarter note.
     pub fn get_ticks_per_quarter_note(&self) -> u16 {
         self.ticks_per_quarter_note
@@ -231,6 +348,243 @@ impl Track {
             .join(" ")
     }
 
+    /// Makes the playback of the current track sound less mechanical by randomly moving the start
+    /// of each note or chord earlier or later and randomly changing the velocity of each note. The
+    /// random variations are generated from a seed, so humanizing the same track with the same
+    /// seed always produces the same result, and jitters of zero leave the track unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `timing_jitter`: A [`Beat`] representing the maximum amount of time that the start of each
+    ///   note or chord can be moved by from its original position. The duration of each note is
+    ///   kept, and notes cannot be moved before the end of the previous note.
+    /// - `velocity_jitter`: A [`u8`] representing the maximum amount that the velocity of each note
+    ///   can be changed by. Velocities are kept between 1 and 127.
+    /// - `seed`: A [`u64`] used to generate the random variations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    /// track.add_rest(Beat::QUARTER);
+    /// track.add_note(Note::from_str("E4").unwrap(), Beat::QUARTER);
+    /// track.humanize(Beat::SIXTEENTH, 10, 42);
+    /// ```
+    pub fn humanize(&mut self, timing_jitter: Beat, velocity_jitter: u8, seed: u64) {
+        let max_offset = self.beat_to_ticks(timing_jitter) as i64;
+        let mut rng = XorShiftRng::new(seed);
+        let mut previous_active = false;
+        let mut previous_offset: i64 = 0;
+        for event in &mut self.events {
+            if event.active && !previous_active {
+                let offset = ((2.0 * rng.next_f32() - 1.0) * max_offset as f32).round() as i64;
+                let delta_ticks = (event.delta_ticks as i64 + offset - previous_offset).max(0);
+                previous_offset += delta_ticks - event.delta_ticks as i64;
+                event.delta_ticks = delta_ticks as u64;
+            }
+            if event.active {
+                let offset = ((2.0 * rng.next_f32() - 1.0) * velocity_jitter as f32).round() as i16;
+                event.velocity = (event.velocity as i16 + offset).clamp(1, 127) as u8;
+            }
+            previous_active = event.active;
+        }
+        self.duration = (self.duration as i64 + previous_offset) as u64;
+    }
+
+    /// Merges notes which are tied, which happens when a note is turned off and the same note is
+    /// turned on again at the same tick, into a single sustained note. The velocity of the first
+    /// note is kept and the timing of every other event is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    /// track.merge_tied_notes();
+    /// assert_eq!(track.get_events().len(), 2);
+    /// assert_eq!(track.get_events()[1].get_delta_ticks(), 720);
+    /// ```
+    pub fn merge_tied_notes(&mut self) {
+        let mut removed = vec![false; self.events.len()];
+        for index in 0..self.events.len() {
+            if self.events[index].active || removed[index] {
+                continue;
+            }
+            for next_index in index + 1..self.events.len() {
+                let next_event = &self.events[next_index];
+                if next_event.delta_ticks > 0 {
+                    break;
+                }
+                if next_event.active && next_event.note == self.events[index].note {
+                    if !removed[next_index] {
+                        removed[index] = true;
+                        removed[next_index] = true;
+                    }
+                    break;
+                }
+            }
+        }
+        let mut carried_ticks = 0;
+        let mut events: Vec<Event> = Vec::new();
+        for (mut event, is_removed) in self.events.drain(..).zip(removed) {
+            if is_removed {
+                carried_ticks += event.delta_ticks;
+            } else {
+                event.delta_ticks += carried_ticks;
+                carried_ticks = 0;
+                events.push(event);
+            }
+        }
+        self.events = events;
+        self.current_delta_ticks += carried_ticks;
+    }
+
+    /// Returns a [`Rhythm`] with the durations of the notes of the current track, ignoring their
+    /// pitch. Notes that start at the same time, such as the notes of a chord, produce a single
+    /// beat with the duration of the longest note, and rests between notes are not included.
+    /// Durations that cannot be represented exactly as a [`Beat`] are rounded to the nearest
+    /// sixty-fourth note.
+    ///
+    /// # Parameters
+    ///
+    /// - `tempo`: The tempo of the rhythm in beats per minute.
+    /// - `time_signature`: A [`Fraction`] representing the time signature of the rhythm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::note::Note;
+    /// use music_tools::track::Track;
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::HALF);
+    /// track.add_note(Note::from_str("G4").unwrap(), Beat::HALF);
+    /// let rhythm = track.to_rhythm(90.0, Fraction::new(4, 4));
+    /// assert_eq!(rhythm.get_beats(), vec![Beat::HALF, Beat::HALF]);
+    /// ```
+    pub fn to_rhythm(&self, tempo: f32, time_signature: Fraction) -> Rhythm {
+        let ticks_per_whole_note = 4 * self.ticks_per_quarter_note as u64;
+        let mut onsets: Vec<(u64, u64)> = Vec::new();
+        for (_, start_tick, duration_ticks) in self.note_spans() {
+            match onsets.last_mut() {
+                Some((last_start, last_duration)) if *last_start == start_tick => {
+                    *last_duration = (*last_duration).max(duration_ticks);
+                }
+                _ => onsets.push((start_tick, duration_ticks)),
+            }
+        }
+        let beats: Vec<Beat> = onsets
+            .iter()
+            .map(|(_, duration_ticks)| ticks_to_beat(*duration_ticks, ticks_per_whole_note))
+            .collect();
+        Rhythm::new(tempo, time_signature, &beats)
+    }
+
+    /// Returns a vector of [`TrackDiff`] with the differences between the notes of the current
+    /// track and the notes of another track, which describe how to turn the current track into
+    /// the other one. Notes that start at the same time with the same duration in both tracks are
+    /// considered unchanged, notes with the same pitch and duration that start at a different time
+    /// are considered moved, and all other notes are considered added or removed. The differences
+    /// are not guaranteed to be the smallest possible set of changes.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: A reference to the [`Track`] to compare the current track with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use music_tools::common::{Beat, Fraction};
+    /// use music_tools::note::Note;
+    /// use music_tools::track::{Track, TrackDiff};
+    /// use std::str::FromStr;
+    ///
+    /// let mut track = Track::new(120.0, Fraction::new(4, 4));
+    /// track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    /// let mut edited = track.clone();
+    /// edited.add_note(Note::from_str("D4").unwrap(), Beat::QUARTER);
+    /// assert_eq!(
+    ///     track.diff(&edited),
+    ///     vec![TrackDiff::Added {
+    ///         note: Note::from_str("D4").unwrap(),
+    ///         start_ticks: 360,
+    ///         duration_ticks: 360,
+    ///     }]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Track) -> Vec<TrackDiff> {
+        let mut removed = self.note_spans();
+        let mut added = other.note_spans();
+        removed.retain(|span| {
+            if let Some(index) = added.iter().position(|other_span| other_span == span) {
+                added.remove(index);
+                return false;
+            }
+            true
+        });
+        let mut diffs: Vec<TrackDiff> = Vec::new();
+        removed.retain(|(note, start_ticks, duration_ticks)| {
+            if let Some(index) = added.iter().position(|(other_note, _, other_duration)| {
+                other_note == note && other_duration == duration_ticks
+            }) {
+                diffs.push(TrackDiff::Moved {
+                    note: *note,
+                    from_ticks: *start_ticks,
+                    to_ticks: added.remove(index).1,
+                    duration_ticks: *duration_ticks,
+                });
+                return false;
+            }
+            true
+        });
+        for (note, start_ticks, duration_ticks) in removed {
+            diffs.push(TrackDiff::Removed {
+                note,
+                start_ticks,
+                duration_ticks,
+            });
+        }
+        for (note, start_ticks, duration_ticks) in added {
+            diffs.push(TrackDiff::Added {
+                note,
+                start_ticks,
+                duration_ticks,
+            });
+        }
+        diffs
+    }
+
+    pub(crate) fn note_spans(&self) -> Vec<(Note, u64, u64)> {
+        let mut spans: Vec<(Note, u64, u64)> = Vec::new();
+        let mut pending: Vec<(Note, u64)> = Vec::new();
+        let mut current_tick = 0;
+        for event in &self.events {
+            current_tick += event.delta_ticks;
+            if event.active {
+                pending.push((event.note, current_tick));
+            } else if let Some(index) = pending.iter().position(|(note, _)| *note == event.note) {
+                let (note, start_tick) = pending.remove(index);
+                spans.push((note, start_tick, current_tick - start_tick));
+            }
+        }
+        spans.sort_by_key(|(note, start_tick, _)| (*start_tick, *note));
+        spans
+    }
+
     fn beat_to_ticks(&self, beat: Beat) -> u64 {
         (4 * self.ticks_per_quarter_note as u64 * beat.get_numerator() as u64)
             / beat.get_denominator() as u64
@@ -247,6 +601,8 @@ impl Default for Track {
             current_delta_ticks: 0,
             current_event: 0,
             events: Vec::new(),
+            program: None,
+            pickup: 0,
         }
     }
 }
@@ -258,10 +614,30 @@ impl Eq for Track {}
 pub struct Event {
     note: Note,
     active: bool,
+    velocity: u8,
     delta_ticks: u64,
 }
 
 impl Event {
+    /// Creates a new event which activates or deactivates a [`Note`] with a given velocity. The
+    /// event does not have any delta ticks, which are given when the event is written with a
+    /// [`MidiWriter`](crate::midi::MidiWriter).
+    ///
+    /// # Parameters
+    ///
+    /// - `note`: The [`Note`] to be activated or deactivated.
+    /// - `active`: A boolean representing whether to activate or deactivate the note.
+    /// - `velocity`: A [`u8`] between 0 and 127 representing how strongly the note is played.
+    ///   Values above 127 are clamped.
+    pub fn new(note: Note, active: bool, velocity: u8) -> Self {
+        Self {
+            note,
+            active,
+            velocity: velocity.min(127),
+            delta_ticks: 0,
+        }
+    }
+
     /// Returns the [`Note`] associated with the current event.
     pub fn get_note(&self) -> Note {
         self.note
@@ -272,8 +648,62 @@ impl Event {
         self.active
     }
 
+    /// Returns a [`u8`] between 0 and 127 representing how strongly the note of the event is
+    /// played.
+    pub fn get_velocity(&self) -> u8 {
+        self.velocity
+    }
+
     /// Returns the amount of MIDI ticks between the last event and the current event.
     pub fn get_delta_ticks(&self) -> u64 {
         self.delta_ticks
     }
 }
+
+/// An enum representing a single difference between the notes of two tracks, where the time at
+/// which each note starts and the duration of each note are measured in MIDI ticks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrackDiff {
+    /// A note which is only present in the second track.
+    Added {
+        /// The [`Note`] that was added.
+        note: Note,
+        /// The time at which the note starts.
+        start_ticks: u64,
+        /// The duration of the note.
+        duration_ticks: u64,
+    },
+    /// A note which is only present in the first track.
+    Removed {
+        /// The [`Note`] that was removed.
+        note: Note,
+        /// The time at which the note started.
+        start_ticks: u64,
+        /// The duration of the note.
+        duration_ticks: u64,
+    },
+    /// A note which is present in both tracks with the same duration but starts at a different
+    /// time.
+    Moved {
+        /// The [`Note`] that was moved.
+        note: Note,
+        /// The time at which the note starts in the first track.
+        from_ticks: u64,
+        /// The time at which the note starts in the second track.
+        to_ticks: u64,
+        /// The duration of the note.
+        duration_ticks: u64,
+    },
+}
+
+fn ticks_to_beat(ticks: u64, ticks_per_whole_note: u64) -> Beat {
+    let common_factor = gcd(ticks, ticks_per_whole_note);
+    let numerator = ticks / common_factor;
+    let denominator = ticks_per_whole_note / common_factor;
+    if numerator <= u8::MAX as u64 && denominator <= u8::MAX as u64 {
+        Beat::new(numerator as u8, denominator as u8)
+    } else {
+        let sixty_fourths = (64 * ticks + ticks_per_whole_note / 2) / ticks_per_whole_note;
+        Beat::new(sixty_fourths.min(u8::MAX as u64) as u8, 64)
+    }
+}
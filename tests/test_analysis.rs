@@ -0,0 +1,81 @@
+use music_tools::analysis::{detect_cadences, detect_mode, find_parallel_fifths};
+use music_tools::chord::Chord;
+use music_tools::common::{CadenceType, PentatonicType, ScaleType, TriadQuality};
+use music_tools::note::Note;
+use music_tools::pitchclass::PitchClass;
+use music_tools::scale::Scale;
+use std::str::FromStr;
+
+#[test]
+fn test_parallel_fifths() {
+    let soprano: Vec<Note> = ["E5", "D5", "E5", "F5", "E5"]
+        .into_iter()
+        .map(|x| Note::from_str(x).unwrap())
+        .collect();
+    let bass: Vec<Note> = ["C4", "G3", "A3", "B3", "C4"]
+        .into_iter()
+        .map(|x| Note::from_str(x).unwrap())
+        .collect();
+    //D5 and G3 form a fifth which is followed by the fifth between E5 and A3
+    assert_eq!(find_parallel_fifths(&soprano, &bass), vec![2]);
+    assert!(find_parallel_fifths(&bass, &bass[1..]).is_empty());
+}
+
+#[test]
+fn test_detect_cadences() {
+    let progression = |numerals: &[&str]| -> Vec<Chord> {
+        numerals
+            .iter()
+            .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap())
+            .collect()
+    };
+    let authentic = progression(&["V", "I"]);
+    assert_eq!(
+        detect_cadences(&authentic, &PitchClass::C),
+        vec![(1, CadenceType::Authentic)]
+    );
+    let mixed = progression(&["I", "IV", "I", "ii", "V7", "vi", "IV", "V", "V7", "I"]);
+    assert_eq!(
+        detect_cadences(&mixed, &PitchClass::C),
+        vec![
+            (2, CadenceType::Plagal),
+            (4, CadenceType::Half),
+            (5, CadenceType::Deceptive),
+            (7, CadenceType::Half),
+            (9, CadenceType::Authentic),
+        ]
+    );
+    assert_eq!(
+        detect_cadences(&progression(&["I", "V"]), &PitchClass::G),
+        vec![(1, CadenceType::Plagal)]
+    );
+    let minor_tonic = progression(&["V", "i"]);
+    assert!(detect_cadences(&minor_tonic, &PitchClass::C).is_empty());
+    let incomplete = [
+        Chord::from_triad(TriadQuality::Major, None, None),
+        Chord::from_numeral("I", PitchClass::C, None).unwrap(),
+    ];
+    assert!(detect_cadences(&incomplete, &PitchClass::C).is_empty());
+    assert!(detect_cadences(&[], &PitchClass::C).is_empty());
+}
+
+#[test]
+fn test_detect_mode() {
+    let melody: Vec<Note> = ["D4", "E4", "F4", "G4", "A4", "B4", "A4", "F4", "D4"]
+        .into_iter()
+        .map(|x| Note::from_str(x).unwrap())
+        .collect();
+    let candidates = [
+        ScaleType::Major,
+        ScaleType::Minor,
+        ScaleType::Dorian,
+        ScaleType::Phrygian,
+    ]
+    .map(|scale_type| Scale::try_new(scale_type, PentatonicType::None).unwrap());
+    let dorian = Scale::try_new(ScaleType::Dorian, PentatonicType::None).unwrap();
+    assert_eq!(
+        detect_mode(&melody, &PitchClass::D, &candidates),
+        Some(dorian)
+    );
+    assert_eq!(detect_mode(&melody, &PitchClass::D, &[]), None);
+}
@@ -0,0 +1,961 @@
+#![cfg(feature = "audio")]
+
+use music_tools::audio::common::{
+    DrumKind, DynamicsCurves, Interpolation, MixingMode, SweepShape, Synth, Tuning, Waveforms,
+};
+use music_tools::audio::mixer::{crossfade, mix_buffers};
+use music_tools::audio::player::{AudioPlayer, BitsPerSample, PlayerConfig, WavMetadata};
+use music_tools::audio::processor::AudioProcessor;
+use music_tools::audio::wavetable::WavetableOscillator;
+use music_tools::common::{Beat, Fraction, PentatonicType, ScaleType};
+use music_tools::melody::Phrase;
+use music_tools::midi::MIDI;
+use music_tools::note::Note;
+use music_tools::rhythm::Rhythm;
+use music_tools::scale::Scale;
+use music_tools::track::Track;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[test]
+fn test_mix_buffers() {
+    let first = vec![0.5, -0.5, 0.25, 0.25];
+    let second = vec![0.75, 0.25];
+    let mixed = mix_buffers(&[first, second], &[1.0, 0.5]);
+    assert_eq!(mixed.len(), 4);
+    //Overlapping region
+    assert_eq!(mixed[0], 0.875);
+    assert_eq!(mixed[1], -0.375);
+    //Tail region only contains the longer buffer
+    assert_eq!(mixed[2], 0.25);
+    assert_eq!(mixed[3], 0.25);
+    //Sums outside of the valid range are clamped
+    let clamped = mix_buffers(&[vec![0.75], vec![0.75]], &[1.0, 1.0]);
+    assert_eq!(clamped, vec![1.0]);
+}
+
+#[test]
+fn test_crossfade() {
+    let first = vec![0.5; 100];
+    let second = vec![0.5; 80];
+    let joined = crossfade(&first, &second, 40);
+    assert_eq!(joined.len(), first.len() + second.len() - 40);
+    //Equal-power curves keep the summed energy of both buffers constant
+    let fade_out = crossfade(&first, &[0.0; 80], 40);
+    let fade_in = crossfade(&[0.0; 100], &second, 40);
+    for index in 60..100 {
+        let energy = fade_out[index].powi(2) + fade_in[index].powi(2);
+        assert!((energy - 0.25).abs() < 0.0001);
+    }
+    //The crossfaded region has no jumps
+    for window in joined.windows(2) {
+        assert!((window[1] - window[0]).abs() < 0.05);
+    }
+    //Overlaps longer than either buffer are clamped
+    assert_eq!(crossfade(&first, &[0.25; 10], 50).len(), 100);
+    assert_eq!(crossfade(&[], &second, 10), second);
+}
+
+#[test]
+fn test_sweep_shapes() {
+    for shape in [SweepShape::Linear, SweepShape::Logarithmic] {
+        assert_eq!(shape.get_frequency(110.0, 1760.0, 0.0), 110.0);
+        assert!((shape.get_frequency(110.0, 1760.0, 1.0) - 1760.0).abs() < 0.01);
+    }
+    //A logarithmic sweep spends the same amount of time on each octave
+    let quarter = SweepShape::Logarithmic.get_frequency(110.0, 1760.0, 0.25);
+    assert!((quarter - 220.0).abs() < 0.01);
+    assert_eq!(SweepShape::Linear.get_frequency(110.0, 1760.0, 0.5), 935.0);
+}
+
+#[test]
+fn test_change_frequency() {
+    let mut processor = AudioProcessor::new();
+    let synth = processor.register_synth(Box::new(WavetableOscillator::default()));
+    processor.start_frequency(440.0, &synth);
+    let before: Vec<f32> = processor.render(Duration::from_millis(10));
+    processor.change_frequency(440.0, 880.0, &synth);
+    let after: Vec<f32> = processor.render(Duration::from_millis(10));
+    assert!(before.iter().any(|sample| *sample != 0.0));
+    assert!(after.iter().any(|sample| *sample != 0.0));
+    //The phase continues from where the previous frequency stopped instead of restarting
+    assert_ne!(after[0], 0.0);
+}
+
+#[test]
+fn test_total_duration() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        assert_eq!(player.buffer_len(), 0);
+        assert_eq!(player.total_duration(), Duration::ZERO);
+        player.set_tempo(120.0);
+        player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+        assert_eq!(player.buffer_len(), 88200);
+        assert!((player.total_duration().as_secs_f32() - 2.0).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_drum_hits() {
+    for kind in [DrumKind::Kick, DrumKind::Snare, DrumKind::HiHat] {
+        let hit = kind.render(Duration::from_millis(500), 44100);
+        assert_eq!(hit.len(), 22050);
+        let energy = |samples: &[f32]| samples.iter().map(|x| x * x).sum::<f32>();
+        let attack = energy(&hit[..2205]);
+        let tail = energy(&hit[hit.len() - 2205..]);
+        assert!(attack > 0.0);
+        assert!(tail < attack * 0.01);
+        assert!(hit.iter().all(|sample| sample.abs() <= 1.0));
+    }
+}
+
+#[test]
+fn test_metronome_grouped() {
+    let mut player = AudioPlayer::new_offline();
+    player
+        .push_metronome_grouped(1, Fraction::new(5, 8), Beat::EIGHTH, &[2, 3])
+        .unwrap();
+    //Five eighth notes at 120 beats per minute
+    let buffer = player.render();
+    assert_eq!(buffer.len(), 5 * 11025);
+    let peaks: Vec<f32> = buffer
+        .chunks(11025)
+        .map(|click| {
+            click
+                .iter()
+                .fold(0.0, |peak: f32, sample| peak.max(sample.abs()))
+        })
+        .collect();
+    let accented = [true, false, true, false, false];
+    for (peak, is_accented) in peaks.iter().zip(accented) {
+        if is_accented {
+            assert_eq!(*peak, peaks[0]);
+        } else {
+            assert!((peak - peaks[0] * 0.5).abs() < 0.0001);
+        }
+    }
+    assert!(player
+        .push_metronome_grouped(1, Fraction::new(5, 8), Beat::EIGHTH, &[3, 3])
+        .is_err());
+    assert!(player
+        .push_metronome_grouped(1, Fraction::new(6, 8), Beat::QUARTER_DOTTED, &[2])
+        .is_ok());
+    assert!(player
+        .push_metronome_grouped(1, Fraction::new(4, 4), Beat::QUARTER, &[])
+        .is_err());
+}
+
+#[derive(Clone, Debug)]
+struct ConstantSynth {
+    value: f32,
+}
+
+impl Synth for ConstantSynth {
+    fn set_volume(&mut self, _volume: f32) {}
+
+    fn clear_voices(&mut self) {}
+
+    fn add_voice(&mut self, _frequency: f32) {}
+
+    fn remove_voice(&mut self, _frequency: f32) {}
+
+    fn get_sample(&mut self) -> f32 {
+        self.value
+    }
+
+    fn advance_sample(&mut self, _sample_rate: u32) {}
+}
+
+#[derive(Clone, Debug)]
+struct LatentSynth {
+    latency_samples: usize,
+    reports_latency: bool,
+    voices: Vec<usize>,
+}
+
+impl Synth for LatentSynth {
+    fn set_volume(&mut self, _volume: f32) {}
+
+    fn clear_voices(&mut self) {
+        self.voices.clear();
+    }
+
+    fn add_voice(&mut self, _frequency: f32) {
+        self.voices.push(0);
+    }
+
+    fn remove_voice(&mut self, _frequency: f32) {
+        self.voices.pop();
+    }
+
+    fn get_latency(&self) -> Duration {
+        if !self.reports_latency {
+            return Duration::ZERO;
+        }
+        //The test renders at 1000 Hz so each sample lasts one millisecond
+        Duration::from_millis(self.latency_samples as u64)
+    }
+
+    fn get_sample(&mut self) -> f32 {
+        let sounding = self
+            .voices
+            .iter()
+            .filter(|elapsed| **elapsed >= self.latency_samples)
+            .count();
+        0.25 * sounding as f32
+    }
+
+    fn advance_sample(&mut self, _sample_rate: u32) {
+        for elapsed in &mut self.voices {
+            *elapsed += 1;
+        }
+    }
+}
+
+#[test]
+fn test_latency_compensation() {
+    let mut midi = MIDI::new();
+    for note in ["C4", "E4"] {
+        //A tick lasts one millisecond at 120 beats per minute with 500 ticks per quarter note
+        let mut track = Track::new_with_ticks(120.0, Fraction::new(4, 4), 500);
+        track.add_note(Note::from_str(note).unwrap(), Beat::QUARTER);
+        midi.add_track(track);
+    }
+    let latent_synths = |reports_latency: bool| {
+        [
+            LatentSynth {
+                latency_samples: 0,
+                reports_latency,
+                voices: Vec::new(),
+            },
+            LatentSynth {
+                latency_samples: 20,
+                reports_latency,
+                voices: Vec::new(),
+            },
+        ]
+    };
+    let mut player = AudioPlayer::new_offline();
+    player.set_sample_rate(1000);
+    player.push_midi_with_synths(&midi, &latent_synths(true), &HashMap::new(), None);
+    let buffer = player.render();
+    let peak = buffer
+        .iter()
+        .fold(0.0, |peak: f32, sample| peak.max(*sample));
+    let onset = buffer.iter().position(|sample| *sample > 0.0).unwrap();
+    assert!((19..=21).contains(&onset));
+    //Both synths start sounding on the same sample
+    assert_eq!(buffer[onset], peak);
+    //Without the reported latencies the slower synth starts sounding after the faster one
+    let mut uncompensated = AudioPlayer::new_offline();
+    uncompensated.set_sample_rate(1000);
+    uncompensated.push_midi_with_synths(&midi, &latent_synths(false), &HashMap::new(), None);
+    let buffer = uncompensated.render();
+    let first_onset = buffer.iter().position(|sample| *sample > 0.0).unwrap();
+    let second_onset = buffer.iter().position(|sample| *sample == peak).unwrap();
+    assert_eq!(first_onset, 0);
+    assert!((19..=21).contains(&second_onset));
+}
+
+#[derive(Clone, Debug)]
+struct LevelSynth {
+    level: f32,
+    voices: usize,
+}
+
+impl Synth for LevelSynth {
+    fn set_volume(&mut self, _volume: f32) {}
+
+    fn clear_voices(&mut self) {
+        self.voices = 0;
+    }
+
+    fn add_voice(&mut self, _frequency: f32) {
+        self.voices += 1;
+    }
+
+    fn remove_voice(&mut self, _frequency: f32) {
+        self.voices = self.voices.saturating_sub(1);
+    }
+
+    fn get_sample(&mut self) -> f32 {
+        self.level * self.voices as f32
+    }
+
+    fn advance_sample(&mut self, _sample_rate: u32) {}
+}
+
+#[test]
+fn test_midi_programs() {
+    let mut violin = Track::new(120.0, Fraction::new(4, 4));
+    violin.set_program(Some(40));
+    violin.add_note(Note::from_str("A4").unwrap(), Beat::QUARTER);
+    let mut midi = MIDI::new();
+    midi.add_track(violin);
+    let synths = [
+        LevelSynth {
+            level: 0.25,
+            voices: 0,
+        },
+        LevelSynth {
+            level: 0.5,
+            voices: 0,
+        },
+    ];
+    let render = |program_map: &HashMap<u8, usize>| {
+        let mut player = AudioPlayer::new_offline();
+        player.push_midi_with_synths(&midi, &synths, program_map, None);
+        player.render()
+    };
+    let positional = render(&HashMap::new());
+    assert!(positional[0] > 0.0);
+    //The violin track is played by the second synth instead of the first one
+    let mapped = render(&HashMap::from([(40, 1)]));
+    assert_eq!(mapped[0], 2.0 * positional[0]);
+    //Indices outside of the list of synths fall back to the position of the track
+    let out_of_range = render(&HashMap::from([(40, 7)]));
+    assert_eq!(out_of_range, positional);
+}
+
+#[test]
+fn test_limiter() {
+    let mut processor = AudioProcessor::new();
+    processor.register_synth(Box::new(ConstantSynth { value: 1.5 }));
+    assert_eq!(processor.get_current_sample(), 1.0);
+    processor.set_limiter(0.8);
+    processor.advance_sample();
+    let limited = processor.get_current_sample();
+    assert!(limited > 0.8 && limited < 1.0);
+    processor.unregister_all_synths();
+    processor.register_synth(Box::new(ConstantSynth { value: -1.2 }));
+    processor.advance_sample();
+    let limited_negative = processor.get_current_sample();
+    assert!(limited_negative < -0.8 && limited_negative > -1.0);
+    //Samples below the threshold are not affected
+    processor.unregister_all_synths();
+    processor.register_synth(Box::new(ConstantSynth { value: 0.5 }));
+    processor.advance_sample();
+    assert_eq!(processor.get_current_sample(), 0.5);
+    processor.disable_limiter();
+    processor.unregister_all_synths();
+    processor.register_synth(Box::new(ConstantSynth { value: 1.5 }));
+    processor.advance_sample();
+    assert_eq!(processor.get_current_sample(), 1.0);
+}
+
+#[test]
+fn test_active_frequencies() {
+    let mut processor = AudioProcessor::new();
+    let synth = processor.register_synth(Box::new(WavetableOscillator::new(
+        Waveforms::SINE_WAVE,
+        1.0,
+        128,
+    )));
+    let other = processor.register_synth(Box::new(ConstantSynth { value: 0.0 }));
+    assert!(processor.active_frequencies().is_empty());
+    processor.start_frequency(440.0, &synth);
+    processor.start_frequency(330.0, &other);
+    assert_eq!(processor.active_frequencies(), vec![330.0, 440.0]);
+    processor.stop_frequency(440.0, &synth);
+    assert_eq!(processor.active_frequencies(), vec![330.0]);
+    processor.start_frequency(440.0, &synth);
+    processor.release_frequency(440.0, &synth);
+    assert!(!processor.active_frequencies().contains(&440.0));
+    processor.stop_all_frequencies();
+    assert!(processor.active_frequencies().is_empty());
+}
+
+#[test]
+fn test_ring_mod() {
+    let mut processor = AudioProcessor::new();
+    processor.register_synth(Box::new(ConstantSynth { value: 0.5 }));
+    let dry = processor.render(Duration::from_millis(10));
+    assert!(dry.iter().all(|sample| *sample == 0.5));
+    processor.set_ring_mod(441.0, 0.0);
+    assert_eq!(processor.render(Duration::from_millis(10)), dry);
+    //A carrier of 441 hertz has a period of 100 samples at 44100 hertz
+    processor.set_ring_mod(441.0, 1.0);
+    let wet = processor.render(Duration::from_millis(10));
+    for (index, sample) in wet.iter().enumerate() {
+        let carrier = (2.0 * std::f32::consts::PI * index as f32 / 100.0).sin();
+        assert!((sample - 0.5 * carrier).abs() < 0.001);
+    }
+    processor.set_ring_mod(441.0, 2.0);
+    assert_eq!(processor.get_ring_mod(), (441.0, 1.0));
+}
+
+#[test]
+fn test_headroom() {
+    let mut processor = AudioProcessor::new();
+    assert_eq!(processor.get_headroom(), 1.0);
+    processor.set_headroom(0.5);
+    processor.set_mixing_mode(MixingMode::Sum);
+    processor.register_synth(Box::new(ConstantSynth { value: 0.9 }));
+    processor.register_synth(Box::new(ConstantSynth { value: -1.5 }));
+    assert_eq!(processor.get_current_sample(), -0.5);
+    processor.unregister_all_synths();
+    let mut oscillator = WavetableOscillator::new(Waveforms::SINE_WAVE, 1.0, 128);
+    oscillator.set_volume(1.0);
+    let synth = processor.register_synth(Box::new(oscillator));
+    processor.start_frequency(440.0, &synth);
+    let samples = processor.render(Duration::from_millis(100));
+    assert!(samples.iter().all(|sample| sample.abs() <= 0.5));
+    assert!(samples.iter().any(|sample| sample.abs() == 0.5));
+    //The limiter compresses samples towards the ceiling instead of clipping them
+    processor.unregister_all_synths();
+    processor.set_limiter(0.8);
+    processor.register_synth(Box::new(ConstantSynth { value: 0.45 }));
+    processor.advance_sample();
+    let limited = processor.get_current_sample();
+    assert!(limited > 0.4 && limited < 0.45);
+    processor.unregister_all_synths();
+    processor.register_synth(Box::new(ConstantSynth { value: 0.3 }));
+    processor.advance_sample();
+    assert_eq!(processor.get_current_sample(), 0.3);
+    processor.set_headroom(2.0);
+    assert_eq!(processor.get_headroom(), 1.0);
+}
+
+#[test]
+fn test_wavetable_release() {
+    let mut oscillator = WavetableOscillator::from([1.0, 1.0].as_slice());
+    oscillator.set_release_time(0.01);
+    oscillator.add_voice(440.0);
+    assert!(!oscillator.is_silent());
+    let sustained = oscillator.get_sample();
+    assert!(sustained > 0.0);
+    oscillator.release_voice(440.0);
+    let mut previous = sustained;
+    let mut samples = 0;
+    while !oscillator.is_silent() {
+        oscillator.advance_sample(1000);
+        let sample = oscillator.get_sample();
+        assert!(sample <= previous);
+        previous = sample;
+        samples += 1;
+    }
+    assert_eq!(previous, 0.0);
+    assert!(samples > 1);
+    assert!(samples <= 10);
+}
+
+#[test]
+fn test_midi_release() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        let mut track = Track::new(120.0, Fraction::new(4, 4));
+        track.add_note(Note::from_str("A4").unwrap(), Beat::QUARTER);
+        track.add_rest(Beat::QUARTER);
+        track.add_note(Note::from_str("A4").unwrap(), Beat::QUARTER);
+        let mut midi = MIDI::new();
+        midi.add_track(track);
+        let mut oscillator = WavetableOscillator::default();
+        oscillator.set_release_time(0.05);
+        player.push_midi(&midi, oscillator, None);
+        let buffer = player.render();
+        //The note off happens after half a second and the release lasts 2205 samples of the rest
+        let rest = &buffer[22050..44100];
+        assert!(rest[..1000].iter().any(|sample| *sample != 0.0));
+        assert!(rest[4410..].iter().all(|sample| *sample == 0.0));
+    }
+}
+
+#[test]
+fn test_wavetable_interpolation() {
+    let sample_at = |mode: Interpolation| {
+        let mut oscillator = WavetableOscillator::from([0.0, 1.0, 0.5, -1.0].as_slice());
+        oscillator.set_volume(0.5);
+        oscillator.set_interpolation(mode);
+        oscillator.add_voice(31.25);
+        oscillator.advance_sample(100);
+        oscillator.get_sample()
+    };
+    let nearest = sample_at(Interpolation::None);
+    let linear = sample_at(Interpolation::Linear);
+    let cubic = sample_at(Interpolation::Cubic);
+    assert_eq!(nearest, 0.5);
+    assert_eq!(linear, 0.4375);
+    assert!((cubic - 0.501_953_1).abs() < 1e-6);
+    assert_ne!(cubic, linear);
+    assert_eq!(
+        WavetableOscillator::default().get_interpolation(),
+        Interpolation::Linear
+    );
+}
+
+#[test]
+fn test_player_sample_rate() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        assert_eq!(player.get_sample_rate(), 44100);
+        player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
+        let default_len = player.buffer_len();
+        player.clear();
+        player.set_sample_rate(48000);
+        assert_eq!(player.get_sample_rate(), 48000);
+        player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
+        assert_eq!(player.buffer_len(), 24000);
+        assert!(player.buffer_len() > default_len);
+        let path = std::env::temp_dir().join("music_tools_test_sample_rate.wav");
+        player
+            .export_wav(path.to_str().unwrap(), BitsPerSample::SIXTEEN)
+            .unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let sample_rate = u32::from_le_bytes([bytes[24], bytes[25], bytes[26], bytes[27]]);
+        assert_eq!(sample_rate, 48000);
+        assert_eq!(bytes.len(), 44 + 2 * 24000);
+    }
+}
+
+#[test]
+fn test_wav_metadata() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        player.push(&Note::from_str("A4").unwrap(), &Beat::SIXTEENTH);
+        let metadata = WavMetadata {
+            title: Some(String::from("Test Tone")),
+            artist: Some(String::from("Music Tools")),
+            comment: Some(String::from("A4")),
+        };
+        let path = std::env::temp_dir().join("music_tools_test_metadata.wav");
+        let plain_path = std::env::temp_dir().join("music_tools_test_no_metadata.wav");
+        player
+            .export_wav_tagged(path.to_str().unwrap(), BitsPerSample::EIGHT, &metadata)
+            .unwrap();
+        player
+            .export_wav_tagged(
+                plain_path.to_str().unwrap(),
+                BitsPerSample::EIGHT,
+                &WavMetadata::default(),
+            )
+            .unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let plain_bytes = std::fs::read(&plain_path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&plain_path).unwrap();
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]) as usize
+        };
+        assert_eq!(read_u32(4), bytes.len() - 8);
+        assert_eq!(plain_bytes.len(), 44 + player.buffer_len());
+        let mut fields = Vec::new();
+        let mut offset = 12;
+        while offset < bytes.len() {
+            let chunk_id = &bytes[offset..offset + 4];
+            let chunk_len = read_u32(offset + 4);
+            if chunk_id == b"LIST" {
+                assert_eq!(&bytes[offset + 8..offset + 12], b"INFO");
+                let mut field_offset = offset + 12;
+                while field_offset < offset + 8 + chunk_len {
+                    let field_id =
+                        String::from_utf8(bytes[field_offset..field_offset + 4].to_vec());
+                    let field_len = read_u32(field_offset + 4);
+                    let text = &bytes[field_offset + 8..field_offset + 8 + field_len - 1];
+                    fields.push((field_id.unwrap(), String::from_utf8(text.to_vec()).unwrap()));
+                    field_offset += 8 + field_len + field_len % 2;
+                }
+            }
+            offset += 8 + chunk_len + chunk_len % 2;
+        }
+        assert_eq!(
+            fields,
+            vec![
+                (String::from("INAM"), String::from("Test Tone")),
+                (String::from("IART"), String::from("Music Tools")),
+                (String::from("ICMT"), String::from("A4")),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_play_now() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        player
+            .play_now(&Note::from_str("C4").unwrap(), &Beat::SIXTEENTH)
+            .unwrap();
+        assert_eq!(player.buffer_len(), 0);
+        player.push(&Note::from_str("E4").unwrap(), &Beat::SIXTEENTH);
+        let queued = player.buffer_len();
+        player
+            .play_now(&Note::from_str("G4").unwrap(), &Beat::SIXTEENTH)
+            .unwrap();
+        assert_eq!(player.buffer_len(), queued);
+    }
+}
+
+#[test]
+fn test_push_scale() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        let tonic = Note::from_str("C4").unwrap();
+        player.push(&tonic, &Beat::EIGHTH);
+        let note_len = player.buffer_len();
+        player.clear();
+        let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+        player.push_scale(&major, &tonic, &Beat::EIGHTH);
+        assert_eq!(player.buffer_len(), 7 * note_len);
+    }
+}
+
+#[test]
+fn test_resample() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+        let original_len = player.buffer_len();
+        let original_duration = player.total_duration();
+        player.resample(22050);
+        assert_eq!(player.get_sample_rate(), 22050);
+        assert_eq!(player.buffer_len(), original_len / 2);
+        let difference = player.total_duration().as_secs_f32() - original_duration.as_secs_f32();
+        assert!(difference.abs() < 0.001);
+        player.resample(44100);
+        assert_eq!(player.buffer_len(), original_len);
+    }
+}
+
+#[test]
+fn test_time_stretch() {
+    if let Ok(mut player) = AudioPlayer::try_new() {
+        player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+        let original_len = player.buffer_len();
+        player.time_stretch(1.0);
+        assert_eq!(player.buffer_len(), original_len);
+        player.time_stretch(2.0);
+        assert_eq!(player.buffer_len(), original_len * 2);
+        player.time_stretch(0.25);
+        assert_eq!(player.buffer_len(), original_len / 2);
+    }
+}
+
+#[test]
+fn test_midi_render_to_wav() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::HALF);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::HALF);
+    let mut midi = MIDI::new();
+    midi.add_track(track);
+    let path = std::env::temp_dir().join("music_tools_test_render_midi.wav");
+    midi.render_to_wav(
+        path.to_str().unwrap(),
+        &[WavetableOscillator::default()],
+        BitsPerSample::SIXTEEN,
+    )
+    .unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[0..4], b"RIFF");
+    let data_len = u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]) as i64;
+    assert_eq!(bytes.len() as i64, 44 + data_len);
+    //Two seconds of 16 bit audio at 44100 Hz
+    assert!((data_len - 2 * 88200).abs() < 2 * 441);
+}
+
+#[test]
+fn test_offline_player() {
+    let mut player = AudioPlayer::new_offline();
+    player.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    assert_eq!(player.buffer_len(), 88200);
+    assert_eq!(player.render().len(), 88200);
+    assert!(player.play().is_err());
+    assert!(player
+        .play_now(&Note::from_str("C4").unwrap(), &Beat::QUARTER)
+        .is_err());
+    assert_eq!(player.buffer_len(), 88200);
+    let path = std::env::temp_dir().join("music_tools_test_offline.wav");
+    player
+        .export_wav(path.to_str().unwrap(), BitsPerSample::SIXTEEN)
+        .unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(bytes.len(), 44 + 2 * 88200);
+}
+
+#[test]
+fn test_bandlimited_wavetable() {
+    let harmonic_amplitude = |table: &[f32], harmonic: usize| {
+        let mut cosine_sum = 0.0;
+        let mut sine_sum = 0.0;
+        for (index, value) in table.iter().enumerate() {
+            let phase = 2.0 * std::f32::consts::PI * (harmonic * index) as f32 / table.len() as f32;
+            cosine_sum += value * phase.cos();
+            sine_sum += value * phase.sin();
+        }
+        2.0 * (cosine_sum * cosine_sum + sine_sum * sine_sum).sqrt() / table.len() as f32
+    };
+    let high_energy = |table: &[f32]| {
+        (9..table.len() / 2)
+            .map(|harmonic| harmonic_amplitude(table, harmonic).powi(2))
+            .sum::<f32>()
+    };
+    let naive = WavetableOscillator::new(Waveforms::SQUARE_WAVE, 1.0, 128).get_wavetable();
+    let bandlimited =
+        WavetableOscillator::new_bandlimited(Waveforms::SQUARE_WAVE, 1.0, 128, 8).get_wavetable();
+    assert_eq!(bandlimited.len(), 128);
+    assert!(bandlimited.iter().all(|value| value.abs() <= 1.0));
+    assert!(high_energy(&naive) > 0.05);
+    assert!(high_energy(&bandlimited) < 0.001);
+    let naive_fundamental = harmonic_amplitude(&naive, 1);
+    let bandlimited_fundamental = harmonic_amplitude(&bandlimited, 1);
+    assert!((naive_fundamental - bandlimited_fundamental).abs() / naive_fundamental < 0.1);
+    let sine = WavetableOscillator::new_bandlimited(Waveforms::SINE_WAVE, 1.0, 128, 64);
+    let default_sine = WavetableOscillator::default();
+    for (value, expected) in sine
+        .get_wavetable()
+        .iter()
+        .zip(default_sine.get_wavetable())
+    {
+        assert!((value - expected).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_push_phrase() {
+    let notes = ["C4", "E4", "G4"].map(|name| Note::from_str(name).unwrap());
+    let rhythm = Rhythm::new(
+        60.0,
+        Fraction::new(4, 4),
+        &[Beat::QUARTER, Beat::QUARTER, Beat::HALF],
+    );
+    let phrase = Phrase::try_new(&notes, rhythm).unwrap();
+    let mut player = AudioPlayer::new_offline();
+    player.push_phrase(&phrase);
+    //Four beats at 60 beats per minute
+    assert_eq!(player.buffer_len(), 4 * 44100);
+    player.clear();
+    player.push(&notes[0], &Beat::QUARTER);
+    assert_eq!(player.buffer_len(), 44100 / 2);
+}
+
+#[test]
+fn test_player_gate() {
+    let note = Note::from_str("A4").unwrap();
+    let mut player = AudioPlayer::new_offline();
+    player.set_gate(0.5);
+    player.push(&note, &Beat::WHOLE);
+    let buffer = player.render();
+    assert!(buffer.len().abs_diff(88200) <= 1);
+    let sounding = buffer.iter().rposition(|sample| *sample != 0.0).unwrap() + 1;
+    assert!(sounding.abs_diff(44100) <= 100);
+    assert!(buffer[sounding..].iter().all(|sample| *sample == 0.0));
+    player.clear();
+    player.set_gate(2.0);
+    player.push(&note, &Beat::WHOLE);
+    assert_eq!(player.buffer_len(), 88200);
+    let buffer = player.render();
+    assert!(buffer[88100..].iter().any(|sample| *sample != 0.0));
+}
+
+#[test]
+fn test_render_integer_samples() {
+    let mut player = AudioPlayer::new_offline();
+    player.mix_in(&[0.0, 1.0, -1.0], 1.0);
+    let samples = player.render_i16();
+    assert_eq!(samples[0], 0);
+    assert!(samples[1].abs_diff(i16::MAX) <= 1);
+    assert!(samples[2].abs_diff(i16::MIN) <= 1);
+    let samples = player.render_i8();
+    assert_eq!(samples[0], 0);
+    assert!(samples[1].abs_diff(i8::MAX) <= 1);
+    let samples = player.render_i24();
+    assert_eq!(samples[0], 0);
+    assert!(samples[1].abs_diff(8388607) <= 1);
+    assert!(samples[2].abs_diff(-8388608) <= 1);
+}
+
+#[test]
+fn test_player_base_frequency() {
+    let note = Note::from_str("A4").unwrap();
+    let mut tuned = AudioPlayer::new_offline();
+    assert_eq!(tuned.get_base_frequency(), 440.0);
+    tuned.set_base_frequency(432.0);
+    let render_frequency = |frequency: f32| {
+        let mut reference = AudioPlayer::new_offline();
+        reference.push(&frequency, &Beat::QUARTER);
+        reference.render()
+    };
+    tuned.push(&note, &Beat::QUARTER);
+    assert_eq!(tuned.render(), render_frequency(432.0));
+    //Notes which already use a reference of 432 hertz are not tuned twice
+    let mut retuned_note = note;
+    retuned_note.set_base_frequency(432.0);
+    tuned.clear();
+    tuned.push(&retuned_note, &Beat::QUARTER);
+    assert_eq!(tuned.render(), render_frequency(432.0));
+    //Frequencies in hertz are played unchanged
+    tuned.clear();
+    tuned.push(&440.0, &Beat::QUARTER);
+    assert_eq!(tuned.render(), render_frequency(440.0));
+    tuned.set_base_frequency(0.0);
+    assert_eq!(tuned.get_base_frequency(), 432.0);
+    assert_eq!(tuned.get_config().base_frequency, 432.0);
+}
+
+#[test]
+fn test_player_config() {
+    let mut player = AudioPlayer::new_offline();
+    let default_config = player.get_config();
+    assert_eq!(default_config.tempo, 120.0);
+    assert_eq!(default_config.speed, 1.0);
+    assert_eq!(default_config.gate, 1.0);
+    assert_eq!(default_config.sample_rate, 44100);
+    assert_eq!(default_config.limiter_threshold, None);
+    assert_eq!(default_config.tuning, Tuning::Equal);
+    player.set_tempo(75.0);
+    player.set_speed(1.6);
+    player.set_gate(0.5);
+    player.set_volume(0.3);
+    player.set_sample_rate(48000);
+    let saved_config = player.get_config();
+    let mut other = AudioPlayer::new_offline();
+    other.apply_config(&saved_config);
+    assert_eq!(other.get_config(), saved_config);
+    assert_eq!(other.get_sample_rate(), 48000);
+    assert_eq!(other.get_speed(), 1.6);
+    //The speed multiplies the tempo of 75 beats per minute, so a whole note lasts two seconds
+    other.push(&Note::from_str("A4").unwrap(), &Beat::WHOLE);
+    assert!(other.buffer_len().abs_diff(96000) <= 1);
+    other.clear();
+    other.apply_config(&PlayerConfig {
+        limiter_threshold: Some(0.8),
+        ..default_config
+    });
+    assert_eq!(other.get_config().tempo, 120.0);
+    assert_eq!(other.get_processor().get_limiter_threshold(), Some(0.8));
+}
+
+#[test]
+fn test_dynamics_curve() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    let note = Note::from_str("A4").unwrap();
+    track.add_event_with_velocity(note, true, 64, 0);
+    track.add_event_with_velocity(note, false, 0, 360);
+    let mut midi = MIDI::new();
+    midi.add_track(track);
+    let peak = |curve: fn(f32) -> f32| {
+        let mut player = AudioPlayer::new_offline();
+        player.set_dynamics_curve(curve);
+        player.push_midi(&midi, WavetableOscillator::default(), None);
+        player
+            .render()
+            .iter()
+            .fold(0.0_f32, |peak, sample| peak.max(sample.abs()))
+    };
+    let linear_peak = peak(DynamicsCurves::LINEAR);
+    let exponential_peak = peak(DynamicsCurves::EXPONENTIAL);
+    assert!(linear_peak > 0.0);
+    assert!(exponential_peak > 0.0);
+    assert!(exponential_peak < linear_peak);
+    let player = AudioPlayer::new_offline();
+    assert_eq!(player.get_velocity_amplitude(0), 0.0);
+    assert_eq!(player.get_velocity_amplitude(127), 1.0);
+    assert!((player.get_velocity_amplitude(64) - 64.0 / 127.0).abs() < 0.0001);
+    assert!((DynamicsCurves::EXPONENTIAL(1.0) - 1.0).abs() < 0.0001);
+    assert_eq!(DynamicsCurves::EXPONENTIAL(0.0), 0.0);
+}
+
+#[test]
+fn test_reverse() {
+    let mut player = AudioPlayer::new_offline();
+    player.push(&Note::from_str("A4").unwrap(), &Beat::QUARTER);
+    player.push(&Note::from_str("C5").unwrap(), &Beat::EIGHTH);
+    let original = player.render();
+    player.reverse();
+    let reversed = player.render();
+    assert_eq!(reversed.len(), original.len());
+    assert_eq!(reversed[0], original[original.len() - 1]);
+    assert_eq!(reversed[reversed.len() - 1], original[0]);
+    player.reverse();
+    assert_eq!(player.render(), original);
+    let mut empty = AudioPlayer::new_offline();
+    empty.reverse();
+    assert_eq!(empty.buffer_len(), 0);
+}
+
+#[test]
+fn test_unison_voices() {
+    let mut oscillator = WavetableOscillator::default();
+    oscillator.set_unison(3, 15.0);
+    oscillator.add_voice(440.0);
+    assert_eq!(oscillator.get_num_voices(), 3);
+    oscillator.add_voice(660.0);
+    assert_eq!(oscillator.get_num_voices(), 6);
+    oscillator.remove_voice(440.0);
+    assert_eq!(oscillator.get_num_voices(), 3);
+    oscillator.release_voice(660.0);
+    for _ in 0..44100 {
+        oscillator.advance_sample(44100);
+    }
+    assert!(oscillator.is_silent());
+    let render = |unison_voices: usize| {
+        let mut oscillator = WavetableOscillator::default();
+        oscillator.set_unison(unison_voices, 15.0);
+        oscillator.add_voice(440.0);
+        let mut samples = Vec::new();
+        for _ in 0..4410 {
+            samples.push(oscillator.get_sample());
+            oscillator.advance_sample(44100);
+        }
+        samples
+    };
+    let single = render(1);
+    let stacked = render(3);
+    assert_eq!(single[0], stacked[0]);
+    assert!(single
+        .iter()
+        .zip(&stacked)
+        .any(|(first, second)| (first - second).abs() > 0.01));
+    let mut no_unison = WavetableOscillator::default();
+    no_unison.set_unison(0, 15.0);
+    no_unison.add_voice(440.0);
+    assert_eq!(no_unison.get_num_voices(), 1);
+    //Each voice keeps the amount of copies it was added with
+    let mut changed = WavetableOscillator::default();
+    changed.set_unison(3, 15.0);
+    changed.add_voice(440.0);
+    changed.set_unison(1, 15.0);
+    changed.add_voice(440.0);
+    assert_eq!(changed.get_num_voices(), 4);
+    changed.remove_voice(440.0);
+    assert_eq!(changed.get_num_voices(), 1);
+    changed.remove_voice(440.0);
+    assert!(changed.is_silent());
+    //Voices that are still playing are removed before voices that are fading out
+    let mut fading = WavetableOscillator::default();
+    fading.set_unison(2, 15.0);
+    fading.set_release_time(0.01);
+    fading.add_voice(440.0);
+    fading.release_voice(440.0);
+    fading.add_voice(440.0);
+    fading.remove_voice(440.0);
+    assert_eq!(fading.get_num_voices(), 2);
+    for _ in 0..44100 {
+        fading.advance_sample(44100);
+    }
+    assert!(fading.is_silent());
+}
+
+#[test]
+fn test_mixing_modes() {
+    let mix = |mode: MixingMode, synths: usize| {
+        let mut processor = AudioProcessor::new();
+        processor.set_mixing_mode(mode);
+        for _ in 0..synths {
+            processor.register_synth(Box::new(ConstantSynth { value: 0.2 }));
+        }
+        processor.get_current_sample()
+    };
+    assert_eq!(
+        AudioProcessor::new().get_mixing_mode(),
+        MixingMode::RmsNormalized
+    );
+    assert!((mix(MixingMode::Sum, 1) - 0.2).abs() < 0.0001);
+    assert!((mix(MixingMode::Sum, 2) - 0.4).abs() < 0.0001);
+    assert!((mix(MixingMode::Sum, 4) - 0.8).abs() < 0.0001);
+    assert!((mix(MixingMode::RmsNormalized, 4) - 0.4).abs() < 0.0001);
+    assert!((mix(MixingMode::Average, 4) - 0.2).abs() < 0.0001);
+    assert_eq!(mix(MixingMode::Average, 0), 0.0);
+    assert_eq!(mix(MixingMode::RmsNormalized, 0), 0.0);
+}
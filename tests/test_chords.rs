@@ -1,5 +1,6 @@
-use music_tools::chord::Chord;
-use music_tools::common::TriadQuality;
+use music_tools::chord::{borrowed_chords, classify_triad, negative_progression, Chord};
+use music_tools::common::{ArpeggioDirection, ChordFunction, DropVoicing, TriadQuality};
+use music_tools::interval::Interval;
 use music_tools::note::Note;
 use music_tools::pitchclass::PitchClass;
 use std::cmp;
@@ -83,3 +84,447 @@ fn test_triads() {
         }
     }
 }
+
+#[test]
+fn test_chord_dissonance() {
+    let major = Chord::from_triad(TriadQuality::Major, None, None);
+    let minor = Chord::from_triad(TriadQuality::Minor, None, None);
+    let cluster = Chord::from(
+        [
+            Interval::PERFECT_UNISON,
+            Interval::MINOR_SECOND,
+            Interval::PERFECT_FIFTH,
+        ]
+        .as_slice(),
+    );
+    let tritone = Chord::from(Interval::TRITONE);
+    assert!(major.dissonance() < cluster.dissonance());
+    assert!(minor.dissonance() < cluster.dissonance());
+    assert!(Chord::from(Interval::PERFECT_FIFTH).dissonance() < tritone.dissonance());
+}
+
+#[test]
+fn test_chord_tones() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    assert_eq!(
+        c_major.chord_tone(ChordFunction::Root).unwrap(),
+        Some(Note::from_str("C4").unwrap())
+    );
+    assert_eq!(
+        c_major.chord_tone(ChordFunction::Third).unwrap(),
+        Some(Note::from_str("E4").unwrap())
+    );
+    assert_eq!(c_major.chord_tone(ChordFunction::Seventh).unwrap(), None);
+    let mut g_seventh = Chord::from_numeral("V7", PitchClass::C, Some(4)).unwrap();
+    g_seventh.set_inversion(1);
+    assert_eq!(
+        g_seventh.chord_tone(ChordFunction::Root).unwrap(),
+        Some(Note::from_str("G5").unwrap())
+    );
+    assert_eq!(
+        g_seventh.chord_tone(ChordFunction::Seventh).unwrap(),
+        Some(Note::from_str("F5").unwrap())
+    );
+    assert!(Chord::from_triad(TriadQuality::Major, None, None)
+        .chord_tone(ChordFunction::Third)
+        .is_err());
+}
+
+#[test]
+fn test_spell_numeral() {
+    assert_eq!(
+        Chord::spell_numeral("IV", "F").unwrap(),
+        vec!["B♭", "D", "F"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("I", "Bb").unwrap(),
+        vec!["B♭", "D", "F"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("ii", "E♭").unwrap(),
+        vec!["F", "A♭", "C"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("V7", "Ab").unwrap(),
+        vec!["E♭", "G", "B♭", "D♭"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("vii°", "D♭").unwrap(),
+        vec!["C", "E♭", "G♭"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("bVI", "D").unwrap(),
+        vec!["B♭", "D", "F"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("V", "G♭").unwrap(),
+        vec!["D♭", "F", "A♭"]
+    );
+    assert_eq!(
+        Chord::spell_numeral("IV", "C♭").unwrap(),
+        vec!["F♭", "A♭", "C♭"]
+    );
+    assert!(Chord::spell_numeral("IV", "H").is_err());
+    assert!(Chord::spell_numeral("VIII", "F").is_err());
+}
+
+#[test]
+fn test_drop_voicings() {
+    let to_notes = |names: &[&str]| -> Vec<Note> {
+        names
+            .iter()
+            .map(|name| Note::from_str(name).unwrap())
+            .collect()
+    };
+    let mut c_major_seventh = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    c_major_seventh.add_interval(Interval::MAJOR_SEVENTH);
+    let mut drop_two = c_major_seventh.clone();
+    drop_two.drop(DropVoicing::Drop2).unwrap();
+    let drop_two_notes: Vec<Note> = Vec::try_from(drop_two).unwrap();
+    assert_eq!(drop_two_notes, to_notes(&["G3", "C4", "E4", "B4"]));
+    let mut drop_three = c_major_seventh.clone();
+    drop_three.drop(DropVoicing::Drop3).unwrap();
+    let drop_three_notes: Vec<Note> = Vec::try_from(drop_three).unwrap();
+    assert_eq!(drop_three_notes, to_notes(&["E3", "C4", "G4", "B4"]));
+    let mut drop_two_and_four = c_major_seventh.clone();
+    drop_two_and_four.drop(DropVoicing::Drop2And4).unwrap();
+    let drop_two_and_four_notes: Vec<Note> = Vec::try_from(drop_two_and_four).unwrap();
+    assert_eq!(drop_two_and_four_notes, to_notes(&["C3", "G3", "E4", "B4"]));
+    let mut inverted = c_major_seventh.clone();
+    inverted.set_inversion(1);
+    inverted.drop(DropVoicing::Drop2).unwrap();
+    let inverted_notes: Vec<Note> = Vec::try_from(inverted).unwrap();
+    assert_eq!(inverted_notes, to_notes(&["B3", "E4", "G4", "C5"]));
+    let mut triad = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(triad.drop(DropVoicing::Drop2And4).is_err());
+}
+
+#[test]
+fn test_prime_form() {
+    let major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::E), None);
+    assert_eq!(major.prime_form(), vec![0, 3, 7]);
+    let minor = Chord::from_triad(TriadQuality::Minor, None, None);
+    assert_eq!(minor.prime_form(), vec![0, 3, 7]);
+    let mut diminished_seventh = Chord::from_triad(TriadQuality::Diminished, None, None);
+    diminished_seventh.add_interval(Interval::MAJOR_SIXTH);
+    assert_eq!(diminished_seventh.prime_form(), vec![0, 3, 6, 9]);
+    let mut dominant_seventh = Chord::from_triad(TriadQuality::Major, None, None);
+    dominant_seventh.add_interval(Interval::MINOR_SEVENTH);
+    assert_eq!(dominant_seventh.prime_form(), vec![0, 2, 5, 8]);
+    let augmented = Chord::from_triad(TriadQuality::Augmented, None, None);
+    assert_eq!(augmented.prime_form(), vec![0, 4, 8]);
+}
+
+#[test]
+fn test_forte_number() {
+    let major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::D), None);
+    assert_eq!(major.forte_number(), Some(String::from("3-11")));
+    let augmented = Chord::from_triad(TriadQuality::Augmented, None, None);
+    assert_eq!(augmented.forte_number(), Some(String::from("3-12")));
+    let mut diminished_seventh = Chord::from_triad(TriadQuality::Diminished, None, None);
+    diminished_seventh.add_interval(Interval::MAJOR_SIXTH);
+    assert_eq!(
+        diminished_seventh.forte_number(),
+        Some(String::from("4-28"))
+    );
+    let mut dominant_seventh = Chord::from_triad(TriadQuality::Major, None, None);
+    dominant_seventh.add_interval(Interval::MINOR_SEVENTH);
+    assert_eq!(dominant_seventh.forte_number(), Some(String::from("4-27")));
+    let mut fifth = Chord::new(None, None);
+    fifth.add_interval(Interval::PERFECT_FIFTH);
+    assert_eq!(fifth.forte_number(), None);
+}
+
+#[test]
+fn test_chord_inversions() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let inversions: Vec<Vec<Note>> = c_major
+        .inversions()
+        .map(|chord| Vec::try_from(chord).unwrap())
+        .collect();
+    let expected: Vec<Vec<Note>> = [["C4", "E4", "G4"], ["E4", "G4", "C5"], ["G4", "C5", "E5"]]
+        .iter()
+        .map(|names| {
+            names
+                .iter()
+                .map(|name| Note::from_str(name).unwrap())
+                .collect()
+        })
+        .collect();
+    assert_eq!(inversions, expected);
+    let inversion_numbers: Vec<u8> = c_major
+        .inversions()
+        .map(|chord| chord.get_inversion())
+        .collect();
+    assert_eq!(inversion_numbers, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_with_bass() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let first_inversion = c_major.with_bass(&PitchClass::E).unwrap();
+    assert_eq!(first_inversion.get_inversion(), 1);
+    assert_eq!(
+        Vec::<Note>::try_from(first_inversion).unwrap(),
+        vec![
+            Note::from_str("E4").unwrap(),
+            Note::from_str("G4").unwrap(),
+            Note::from_str("C5").unwrap()
+        ]
+    );
+    assert_eq!(
+        c_major.with_bass(&PitchClass::C).unwrap().get_inversion(),
+        0
+    );
+    assert!(c_major.with_bass(&PitchClass::F).is_err());
+    let no_tonic = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(no_tonic.with_bass(&PitchClass::E).is_err());
+}
+
+#[test]
+fn test_common_tones() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    assert_eq!(
+        c_major.common_tones(&a_minor).unwrap(),
+        vec![PitchClass::C, PitchClass::E]
+    );
+    assert_eq!(
+        c_major.common_notes(&a_minor).unwrap(),
+        vec![Note::from_str("C4").unwrap(), Note::from_str("E4").unwrap()]
+    );
+    let a_minor_low = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(2));
+    assert!(c_major.common_notes(&a_minor_low).unwrap().is_empty());
+    let f_sharp_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::F_SHARP), None);
+    assert!(c_major.common_tones(&f_sharp_major).unwrap().is_empty());
+    let no_tonic = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(c_major.common_tones(&no_tonic).is_err());
+    assert!(c_major.common_notes(&f_sharp_major).is_err());
+}
+
+#[test]
+fn test_constrain_to_range() {
+    let to_notes = |names: &[&str]| -> Vec<Note> {
+        names
+            .iter()
+            .map(|name| Note::from_str(name).unwrap())
+            .collect()
+    };
+    let mut dominant_ninth = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(2));
+    dominant_ninth.add_interval(Interval::MINOR_SEVENTH);
+    dominant_ninth.add_interval(Interval::MAJOR_NINTH);
+    dominant_ninth.add_interval(Interval::MAJOR_TENTH);
+    dominant_ninth
+        .constrain_to_range(Note::from_str("C4").unwrap(), Note::from_str("B4").unwrap())
+        .unwrap();
+    let constrained_notes: Vec<Note> = Vec::try_from(dominant_ninth).unwrap();
+    assert_eq!(
+        constrained_notes,
+        to_notes(&["C4", "D4", "E4", "G4", "A#4"])
+    );
+    let mut inverted = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(5));
+    inverted.set_inversion(2);
+    inverted
+        .constrain_to_range(Note::from_str("F3").unwrap(), Note::from_str("E4").unwrap())
+        .unwrap();
+    let inverted_notes: Vec<Note> = Vec::try_from(inverted.clone()).unwrap();
+    assert_eq!(inverted_notes, to_notes(&["A3", "C4", "E4"]));
+    assert_eq!(inverted.get_inversion(), 0);
+    let mut c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    assert!(c_major
+        .constrain_to_range(Note::from_str("C4").unwrap(), Note::from_str("E4").unwrap())
+        .is_err());
+    let mut no_octave = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), None);
+    assert!(no_octave
+        .constrain_to_range(Note::from_str("C4").unwrap(), Note::from_str("B4").unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_root_semitones() {
+    let mut c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    c_major.set_inversion(2);
+    assert_eq!(c_major.root_semitones(), vec![0, 4, 7]);
+    let inverted_semitones: Vec<u64> = c_major
+        .get_intervals()
+        .iter()
+        .map(|interval| interval.get_value())
+        .collect();
+    assert_eq!(inverted_semitones, vec![7, 12, 16]);
+    let mut dominant_seventh = Chord::from_triad(TriadQuality::Major, None, None);
+    dominant_seventh.add_interval(Interval::MINOR_SEVENTH);
+    dominant_seventh.set_inversion(1);
+    assert_eq!(dominant_seventh.root_semitones(), vec![0, 4, 7, 10]);
+}
+
+#[test]
+fn test_chord_arpeggiate() {
+    let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let to_notes = |names: &[&str]| -> Vec<Note> {
+        names
+            .iter()
+            .map(|name| Note::from_str(name).unwrap())
+            .collect()
+    };
+    assert_eq!(
+        chord.arpeggiate(ArpeggioDirection::UpDown, 5).unwrap(),
+        to_notes(&["C4", "E4", "G4", "E4", "C4"])
+    );
+    assert_eq!(
+        chord.arpeggiate(ArpeggioDirection::Up, 5).unwrap(),
+        to_notes(&["C4", "E4", "G4", "C4", "E4"])
+    );
+    assert_eq!(
+        chord.arpeggiate(ArpeggioDirection::Down, 4).unwrap(),
+        to_notes(&["G4", "E4", "C4", "G4"])
+    );
+    assert!(chord
+        .arpeggiate(ArpeggioDirection::Up, 0)
+        .unwrap()
+        .is_empty());
+    let incomplete = Chord::from_triad(TriadQuality::Minor, None, None);
+    assert!(incomplete.arpeggiate(ArpeggioDirection::Up, 3).is_err());
+}
+
+#[test]
+fn test_borrowed_chords() {
+    let chords = borrowed_chords(&PitchClass::C, 4);
+    assert_eq!(chords.len(), 6);
+    let b_flat_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::B_FLAT), Some(4));
+    let f_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::F), Some(4));
+    let a_flat_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::A_FLAT), Some(4));
+    let e_flat_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::E_FLAT), Some(4));
+    for chord in [b_flat_major, f_minor, a_flat_major, e_flat_major] {
+        assert!(chords.contains(&chord));
+    }
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    assert!(!chords.contains(&c_major));
+    let g_chords = borrowed_chords(&PitchClass::G, 3);
+    let f_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::F), Some(4));
+    assert!(g_chords.contains(&f_major));
+}
+
+#[test]
+fn test_enharmonic_conflicts() {
+    let conflicting = ["C", "E", "G#", "Ab"];
+    assert!(Chord::has_enharmonic_conflict(&conflicting).unwrap());
+    let respelled = Chord::respell_consistently(&conflicting).unwrap();
+    assert_eq!(respelled, vec!["C", "E", "G#", "G#"]);
+    let respelled_refs: Vec<&str> = respelled.iter().map(|name| name.as_str()).collect();
+    assert!(!Chord::has_enharmonic_conflict(&respelled_refs).unwrap());
+    assert!(Chord::has_enharmonic_conflict(&["B#", "E", "G", "C"]).unwrap());
+    assert_eq!(
+        Chord::respell_consistently(&["B#", "E", "G", "C"]).unwrap(),
+        vec!["B#", "E", "G", "B#"]
+    );
+    assert!(!Chord::has_enharmonic_conflict(&["C", "E", "G", "C"]).unwrap());
+    let names = Chord::spell_numeral("IV", "F").unwrap();
+    let name_refs: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    assert!(!Chord::has_enharmonic_conflict(&name_refs).unwrap());
+    assert!(Chord::has_enharmonic_conflict(&["C", "H"]).is_err());
+    assert!(Chord::respell_consistently(&["C", "H"]).is_err());
+}
+
+#[test]
+fn test_chord_substitutions() {
+    let g7 = Chord::from_numeral("V7", PitchClass::C, Some(3)).unwrap();
+    let substitutions = g7.substitutions().unwrap();
+    let d_flat7 = Chord::from_numeral("bII7", PitchClass::C, Some(4)).unwrap();
+    let d_minor7 = Chord::from_numeral("ii7", PitchClass::C, Some(3)).unwrap();
+    assert_eq!(substitutions, vec![d_flat7, d_minor7]);
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    assert_eq!(c_major.substitutions().unwrap(), vec![a_minor.clone()]);
+    assert_eq!(a_minor.substitutions().unwrap(), vec![c_major]);
+    let no_octave = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::E), None);
+    assert_eq!(
+        no_octave.substitutions().unwrap(),
+        vec![Chord::from_triad(
+            TriadQuality::Major,
+            Some(PitchClass::G),
+            None
+        )]
+    );
+    let diminished = Chord::from_triad(TriadQuality::Diminished, Some(PitchClass::B), Some(4));
+    assert!(diminished.substitutions().unwrap().is_empty());
+    let incomplete = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(incomplete.substitutions().is_err());
+}
+
+#[test]
+fn test_to_midi_indices() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    assert_eq!(c_major.to_midi_indices().unwrap(), vec![60, 64, 67]);
+    let mut inverted = c_major.clone();
+    inverted.set_inversion(1);
+    assert_eq!(inverted.to_midi_indices().unwrap(), vec![64, 67, 72]);
+    let high = Chord::from_triad(TriadQuality::Major, Some(PitchClass::G), Some(9));
+    assert!(high.to_midi_indices().is_err());
+    let incomplete = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), None);
+    assert!(incomplete.to_midi_indices().is_err());
+}
+
+#[test]
+fn test_negative_harmony() {
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let c_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::C), Some(4));
+    assert_eq!(c_major.negative(&PitchClass::C), c_minor);
+    assert_eq!(c_minor.negative(&PitchClass::C), c_major);
+    let mut g_dominant = Chord::from_triad(TriadQuality::Major, Some(PitchClass::G), Some(3));
+    g_dominant.add_interval(Interval::MINOR_SEVENTH);
+    let mut d_half_diminished =
+        Chord::from_triad(TriadQuality::Diminished, Some(PitchClass::D), Some(3));
+    d_half_diminished.add_interval(Interval::MINOR_SEVENTH);
+    assert_eq!(g_dominant.negative(&PitchClass::C), d_half_diminished);
+    let a_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::A), None);
+    let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), None);
+    assert_eq!(a_major.negative(&PitchClass::A), a_minor);
+    let shape = Chord::from_triad(TriadQuality::Major, None, None);
+    assert_eq!(
+        shape.negative(&PitchClass::C),
+        Chord::from_triad(TriadQuality::Minor, None, None)
+    );
+    let progression = ["ii", "V", "I"]
+        .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap());
+    let expected = ["bVII", "iv", "i"]
+        .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap());
+    assert_eq!(negative_progression(&progression, &PitchClass::C), expected);
+}
+
+#[test]
+fn test_same_pitch_classes() {
+    let low = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(3));
+    let mut high = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(5));
+    high.set_inversion(2);
+    assert!(low.same_pitch_classes(&high));
+    assert_ne!(low, high);
+    let mut doubled = low.clone();
+    doubled.add_interval(Interval::PERFECT_OCTAVE);
+    assert!(doubled.same_pitch_classes(&high));
+    let a_minor = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    assert!(!low.same_pitch_classes(&a_minor));
+    let shape = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(!low.same_pitch_classes(&shape));
+    assert!(shape.same_pitch_classes(&Chord::from_triad(TriadQuality::Major, None, Some(2))));
+}
+
+#[test]
+fn test_classify_triad() {
+    assert_eq!(classify_triad(&[0, 4, 7]), Some(TriadQuality::Major));
+    assert_eq!(classify_triad(&[0, 3, 6]), Some(TriadQuality::Diminished));
+    assert_eq!(classify_triad(&[7, 11, 14]), Some(TriadQuality::Major));
+    for quality in [
+        TriadQuality::Major,
+        TriadQuality::Minor,
+        TriadQuality::Sus2,
+        TriadQuality::Sus4,
+        TriadQuality::Augmented,
+        TriadQuality::Diminished,
+    ] {
+        let chord = Chord::from_triad(quality, None, None);
+        assert_eq!(classify_triad(&chord.root_semitones()), Some(quality));
+    }
+    assert_eq!(classify_triad(&[0, 4]), None);
+    assert_eq!(classify_triad(&[0, 1, 2]), None);
+    assert_eq!(classify_triad(&[]), None);
+}
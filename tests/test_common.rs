@@ -0,0 +1,35 @@
+use music_tools::common::{Beat, TapTempo};
+use std::time::Duration;
+
+#[test]
+fn test_tap_tempo() {
+    let mut tap_tempo = TapTempo::new(4);
+    assert_eq!(tap_tempo.bpm(), None);
+    tap_tempo.tap(Duration::from_millis(0));
+    assert_eq!(tap_tempo.bpm(), None);
+    for index in 1..10 {
+        tap_tempo.tap(Duration::from_millis(500 * index));
+        assert!((tap_tempo.bpm().unwrap() - 120.0).abs() < 0.01);
+    }
+    //Only the most recent taps are taken into account
+    tap_tempo.tap(Duration::from_millis(5000));
+    tap_tempo.tap(Duration::from_millis(6000));
+    tap_tempo.tap(Duration::from_millis(7000));
+    tap_tempo.tap(Duration::from_millis(8000));
+    assert!((tap_tempo.bpm().unwrap() - 60.0).abs() < 0.01);
+}
+
+#[test]
+fn test_dotted_beats() {
+    assert_eq!(Beat::QUARTER.dotted(), Beat::QUARTER_DOTTED);
+    assert_eq!(Beat::WHOLE.dotted(), Beat::WHOLE_DOTTED);
+    assert_eq!(Beat::THIRTYSECOND.dotted(), Beat::THIRTYSECOND_DOTTED);
+    assert_eq!(Beat::QUARTER.double_dotted(), Beat::new(7, 16));
+    assert_eq!(
+        Beat::EIGHTH.double_dotted().get_as_float(),
+        Beat::EIGHTH.get_as_float() * 1.75
+    );
+    assert_eq!(Beat::new(100, 1).dotted(), Beat::new(150, 1));
+    assert_eq!(Beat::new(64, 1).double_dotted(), Beat::new(112, 1));
+    assert_eq!(Beat::new(2, 128).dotted(), Beat::new(3, 128));
+}
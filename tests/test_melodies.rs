@@ -0,0 +1,61 @@
+use music_tools::common::{Beat, Fraction, PentatonicType, ScaleType};
+use music_tools::melody::{invert_melody, random_melody, retrograde, Phrase};
+use music_tools::note::Note;
+use music_tools::pitchclass::PitchClass;
+use music_tools::rhythm::Rhythm;
+use music_tools::scale::Scale;
+use std::str::FromStr;
+
+fn to_notes(names: &[&str]) -> Vec<Note> {
+    names.iter().map(|x| Note::from_str(x).unwrap()).collect()
+}
+
+#[test]
+fn test_retrograde_and_inversion() {
+    let melody = to_notes(&["C4", "E4", "G4"]);
+    assert_eq!(retrograde(&melody), to_notes(&["G4", "E4", "C4"]));
+    assert_eq!(retrograde(&retrograde(&melody)), melody);
+    let axis = Note::from_str("C4").unwrap();
+    let inverted = invert_melody(&melody, &axis);
+    assert_eq!(inverted, to_notes(&["C4", "Ab3", "F3"]));
+    assert_eq!(invert_melody(&inverted, &axis), melody);
+}
+
+#[test]
+fn test_phrase() {
+    let notes = to_notes(&["C4", "E4", "G4"]);
+    let rhythm = Rhythm::new(
+        120.0,
+        Fraction::new(4, 4),
+        &[Beat::QUARTER, Beat::QUARTER, Beat::HALF],
+    );
+    let phrase = Phrase::try_new(&notes, rhythm.clone()).unwrap();
+    let pairs: Vec<(Note, Beat)> = phrase.iter().collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (notes[0], Beat::QUARTER),
+            (notes[1], Beat::QUARTER),
+            (notes[2], Beat::HALF),
+        ]
+    );
+    assert_eq!(phrase.get_notes(), notes);
+    assert_eq!(phrase.get_rhythm(), rhythm);
+    assert!(Phrase::try_new(&notes[..2], rhythm).is_err());
+}
+
+#[test]
+fn test_random_melody() {
+    let dorian = Scale::try_new(ScaleType::Dorian, PentatonicType::None).unwrap();
+    let tonic = Note::from_str("D3").unwrap();
+    let melody = random_melody(&dorian, &tonic, 32, 2, 7);
+    assert_eq!(melody.len(), 32);
+    assert_eq!(melody, random_melody(&dorian, &tonic, 32, 2, 7));
+    let pitch_classes = dorian.to_pitch_classes(PitchClass::D);
+    assert!(melody
+        .iter()
+        .all(|note| pitch_classes.contains(&note.get_pitch_class())));
+    let highest = Note::from_str("D5").unwrap();
+    assert!(melody.iter().all(|note| *note >= tonic && *note <= highest));
+    assert!(random_melody(&dorian, &tonic, 0, 2, 7).is_empty());
+}
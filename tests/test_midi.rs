@@ -0,0 +1,586 @@
+use music_tools::chord::Chord;
+use music_tools::common::{Beat, Fraction, TriadQuality};
+use music_tools::midi::{ImportOptions, MidiWriter, MIDI};
+use music_tools::note::Note;
+use music_tools::pitchclass::PitchClass;
+use music_tools::track::{Event, Track, TrackDiff};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[test]
+fn test_events_with_time() {
+    let mut melody = Track::new(120.0, Fraction::new(4, 4));
+    melody.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    melody.add_rest(Beat::QUARTER);
+    melody.add_note(Note::from_str("E4").unwrap(), Beat::HALF);
+    let mut bass = Track::new(120.0, Fraction::new(4, 4));
+    bass.add_note(Note::from_str("C3").unwrap(), Beat::WHOLE);
+    let mut midi = MIDI::new();
+    midi.add_track(melody);
+    midi.add_track(bass);
+
+    let events = midi.events_with_time(120.0);
+    assert_eq!(events.len(), 6);
+    let note_ons: Vec<(usize, Note, Duration)> = events
+        .iter()
+        .filter(|(_, event, _)| event.is_active())
+        .map(|(track_index, event, time)| (*track_index, event.get_note(), *time))
+        .collect();
+    assert_eq!(
+        note_ons[0],
+        (0, Note::from_str("C4").unwrap(), Duration::ZERO)
+    );
+    assert_eq!(
+        note_ons[1],
+        (1, Note::from_str("C3").unwrap(), Duration::ZERO)
+    );
+    assert_eq!(
+        note_ons[2],
+        (0, Note::from_str("E4").unwrap(), Duration::from_secs(1))
+    );
+    assert_eq!(events[5].2, Duration::from_secs(2));
+
+    let slower_events = midi.events_with_time(60.0);
+    assert_eq!(slower_events[5].2, Duration::from_secs(4));
+}
+
+#[test]
+fn test_events_with_tempo_changes() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::WHOLE);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::WHOLE);
+    let mut midi = MIDI::new();
+    midi.add_track(track);
+    midi.add_tempo_change(0, 1000000);
+    midi.add_tempo_change(1440, 250000);
+    //Four quarter notes at 60 bpm followed by four quarter notes at 240 bpm
+    let times: Vec<Duration> = midi
+        .events_with_time(120.0)
+        .iter()
+        .map(|(_, _, time)| *time)
+        .collect();
+    assert_eq!(
+        times,
+        vec![
+            Duration::ZERO,
+            Duration::from_secs(4),
+            Duration::from_secs(4),
+            Duration::from_secs(5)
+        ]
+    );
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(4500), 120.0),
+        Some(Chord::from(Note::from_str("E4").unwrap()))
+    );
+    assert_eq!(midi.chord_at(Duration::from_millis(5500), 120.0), None);
+
+    //The base tempo is used until the first tempo change
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::WHOLE);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::WHOLE);
+    let mut midi = MIDI::new();
+    midi.add_track(track);
+    midi.add_tempo_change(1440, 250000);
+    assert_eq!(
+        midi.get_tempo_map(60.0),
+        vec![(Duration::from_secs(4), 250000)]
+    );
+    let times: Vec<Duration> = midi
+        .events_with_time(60.0)
+        .iter()
+        .map(|(_, _, time)| *time)
+        .collect();
+    assert_eq!(
+        times,
+        vec![
+            Duration::ZERO,
+            Duration::from_secs(4),
+            Duration::from_secs(4),
+            Duration::from_secs(5)
+        ]
+    );
+    assert_eq!(midi.events_with_time(120.0)[3].2, Duration::from_secs(3));
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(2500), 120.0),
+        Some(Chord::from(Note::from_str("E4").unwrap()))
+    );
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(2500), 60.0),
+        Some(Chord::from(Note::from_str("C4").unwrap()))
+    );
+}
+
+#[test]
+fn test_chord_at() {
+    let mut chords = Track::new(120.0, Fraction::new(4, 4));
+    chords.add_rest(Beat::QUARTER);
+    chords
+        .add_chord(
+            Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4)),
+            Beat::HALF,
+        )
+        .unwrap();
+    let mut bass = Track::new(120.0, Fraction::new(4, 4));
+    bass.add_note(Note::from_str("A2").unwrap(), Beat::QUARTER);
+    let mut midi = MIDI::new();
+    midi.add_track(chords);
+    midi.add_track(bass);
+
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(100), 120.0),
+        Some(Chord::from(Note::from_str("A2").unwrap()))
+    );
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    for millis in [500, 800, 1499] {
+        assert_eq!(
+            midi.chord_at(Duration::from_millis(millis), 120.0),
+            Some(c_major.clone())
+        );
+    }
+    assert_eq!(midi.chord_at(Duration::from_millis(1500), 120.0), None);
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(1500), 60.0),
+        Some(c_major.clone())
+    );
+    //The bass ends and the chord starts at exactly 500 milliseconds
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(500), 120.0),
+        Some(c_major.clone())
+    );
+
+    //A note that is played by two tracks at once is only added once
+    let mut doubled = Track::new(120.0, Fraction::new(4, 4));
+    doubled.add_note(Note::from_str("C4").unwrap(), Beat::HALF);
+    midi.add_track(doubled);
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(800), 120.0),
+        Some(c_major.clone())
+    );
+    //The C4 of the chord still sounds after the doubled C4 ends
+    assert_eq!(
+        midi.chord_at(Duration::from_millis(1200), 120.0),
+        Some(c_major)
+    );
+}
+
+#[test]
+fn test_track_programs() {
+    let mut violin = Track::new(120.0, Fraction::new(4, 4));
+    violin.set_program(Some(40));
+    violin.add_note(Note::from_str("E5").unwrap(), Beat::WHOLE);
+    let mut piano = Track::new(120.0, Fraction::new(4, 4));
+    piano.add_note(Note::from_str("C4").unwrap(), Beat::WHOLE);
+    let program_map = HashMap::from([(0, 0), (40, 2)]);
+    assert_eq!(violin.get_program(), Some(40));
+    assert_eq!(violin.get_synth_index(&program_map), Some(2));
+    assert_eq!(piano.get_program(), None);
+    assert_eq!(piano.get_synth_index(&program_map), None);
+    piano.set_program(Some(1));
+    assert_eq!(piano.get_synth_index(&program_map), None);
+    let mut midi = MIDI::new();
+    midi.add_track(violin);
+    let path = std::env::temp_dir().join("music_tools_test_programs.mid");
+    midi.export_to_file(path.to_str().unwrap()).unwrap();
+    let imported = MIDI::import_from_file(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(imported.get_tracks()[0].get_program(), Some(40));
+}
+
+#[test]
+fn test_import_options() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::QUARTER);
+    let mut other_track = Track::new(120.0, Fraction::new(4, 4));
+    other_track.add_note(Note::from_str("G4").unwrap(), Beat::HALF);
+    let mut midi = MIDI::new();
+    midi.add_track(track.clone());
+    midi.add_track(track);
+    midi.add_track(other_track);
+    let path = std::env::temp_dir().join("music_tools_test_import_options.mid");
+    midi.export_to_file(path.to_str().unwrap()).unwrap();
+    let imported = MIDI::import_from_file(path.to_str().unwrap()).unwrap();
+    let deduplicated = MIDI::import_with_options(
+        path.to_str().unwrap(),
+        ImportOptions {
+            deduplicate_tracks: true,
+            drop_empty: true,
+        },
+    )
+    .unwrap();
+    let with_empty = MIDI::import_with_options(
+        path.to_str().unwrap(),
+        ImportOptions {
+            deduplicate_tracks: false,
+            drop_empty: false,
+        },
+    )
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(imported.get_num_tracks(), 3);
+    assert_eq!(deduplicated.get_num_tracks(), 2);
+    assert!(with_empty.get_num_tracks() > 3);
+    assert_eq!(
+        deduplicated.get_tracks()[1].get_events(),
+        imported.get_tracks()[2].get_events()
+    );
+}
+
+#[test]
+fn test_import_tracks() {
+    let mut midi = MIDI::new();
+    for name in ["C4", "E4", "G4"] {
+        let mut track = Track::new(120.0, Fraction::new(4, 4));
+        track.add_note(Note::from_str(name).unwrap(), Beat::QUARTER);
+        midi.add_track(track);
+    }
+    let path = std::env::temp_dir().join("music_tools_test_import_tracks.mid");
+    midi.export_to_file(path.to_str().unwrap()).unwrap();
+    //The first track of the file holds the tempo and time signature
+    let imported = MIDI::import_tracks(path.to_str().unwrap(), &[2]).unwrap();
+    let reordered = MIDI::import_tracks(path.to_str().unwrap(), &[3, 1]).unwrap();
+    let conductor = MIDI::import_tracks(path.to_str().unwrap(), &[0]).unwrap();
+    let out_of_range = MIDI::import_tracks(path.to_str().unwrap(), &[4]);
+    let repeated = MIDI::import_tracks(path.to_str().unwrap(), &[1, 1]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(imported.get_num_tracks(), 1);
+    assert_eq!(
+        imported.get_tracks()[0].get_events()[0].get_note(),
+        Note::from_str("E4").unwrap()
+    );
+    assert_eq!(reordered.get_num_tracks(), 2);
+    assert_eq!(
+        reordered.get_tracks()[0].get_events()[0].get_note(),
+        Note::from_str("C4").unwrap()
+    );
+    assert_eq!(conductor.get_num_tracks(), 1);
+    assert!(conductor.get_tracks()[0].get_events().is_empty());
+    assert!(out_of_range.is_err());
+    assert!(repeated.is_err());
+}
+
+#[test]
+fn test_notes_in_range() {
+    let to_notes = |names: &[&str]| -> Vec<Note> {
+        names
+            .iter()
+            .map(|name| Note::from_str(name).unwrap())
+            .collect()
+    };
+    let mut melody = Track::new(120.0, Fraction::new(4, 4));
+    for name in ["C3", "G4", "E6", "C5"] {
+        melody.add_note(Note::from_str(name).unwrap(), Beat::QUARTER);
+    }
+    let mut bass = Track::new(120.0, Fraction::new(4, 4));
+    bass.add_rest(Beat::EIGHTH);
+    bass.add_note(Note::from_str("C1").unwrap(), Beat::HALF);
+    bass.add_note(Note::from_str("C4").unwrap(), Beat::HALF);
+    let mut midi = MIDI::new();
+    midi.add_track(melody);
+    midi.add_track(bass);
+
+    assert_eq!(
+        midi.notes_in_range(Note::from_str("C4").unwrap(), Note::from_str("C5").unwrap()),
+        to_notes(&["G4", "C4", "C5"])
+    );
+    assert_eq!(
+        midi.notes_in_range(Note::from_str("C1").unwrap(), Note::from_str("C3").unwrap()),
+        to_notes(&["C3", "C1"])
+    );
+    assert!(midi
+        .notes_in_range(Note::from_str("C7").unwrap(), Note::from_str("C8").unwrap())
+        .is_empty());
+}
+
+#[test]
+fn test_pitch_class_durations() {
+    let mut melody = Track::new(120.0, Fraction::new(4, 4));
+    melody.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    melody.add_note(Note::from_str("G4").unwrap(), Beat::QUARTER);
+    let mut bass = Track::new(120.0, Fraction::new(4, 4));
+    bass.add_note(Note::from_str("C3").unwrap(), Beat::QUARTER);
+    let mut midi = MIDI::new();
+    midi.add_track(melody);
+    midi.add_track(bass);
+    let durations = midi.pitch_class_durations();
+    //C sounds for two beats and G for one
+    assert_eq!(durations[PitchClass::C.get_value() as usize], 0.5);
+    assert_eq!(durations[PitchClass::G.get_value() as usize], 0.25);
+    assert!(durations
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != 0 && *index != 7)
+        .all(|(_, duration)| *duration == 0.0));
+    assert_eq!(MIDI::new().pitch_class_durations(), [0.0; 12]);
+    //Long durations are not capped
+    let mut drone = Track::new(120.0, Fraction::new(4, 4));
+    for _ in 0..10 {
+        drone.add_note(Note::from_str("D2").unwrap(), Beat::WHOLE);
+    }
+    let mut long_midi = MIDI::new();
+    long_midi.add_track(drone);
+    assert_eq!(
+        long_midi.pitch_class_durations()[PitchClass::D.get_value() as usize],
+        10.0
+    );
+}
+
+#[test]
+fn test_track_humanize() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    for name in ["C4", "E4", "G4", "C5"] {
+        track.add_note(Note::from_str(name).unwrap(), Beat::QUARTER);
+        track.add_rest(Beat::EIGHTH);
+    }
+    track
+        .add_chord(
+            Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4)),
+            Beat::HALF,
+        )
+        .unwrap();
+
+    let mut unchanged = track.clone();
+    unchanged.humanize(Beat::new(0, 1), 0, 7);
+    assert_eq!(unchanged, track);
+
+    let mut humanized = track.clone();
+    humanized.humanize(Beat::SIXTEENTH, 10, 7);
+    let mut same_seed = track.clone();
+    same_seed.humanize(Beat::SIXTEENTH, 10, 7);
+    assert_eq!(humanized, same_seed);
+    assert_ne!(humanized, track);
+
+    let mut original_tick = 0;
+    let mut humanized_tick = 0;
+    for (original, event) in track.get_events().iter().zip(humanized.get_events()) {
+        original_tick += original.get_delta_ticks() as i64;
+        humanized_tick += event.get_delta_ticks() as i64;
+        assert_eq!(event.get_note(), original.get_note());
+        assert!((humanized_tick - original_tick).abs() <= 90);
+        if event.is_active() {
+            assert_eq!(original.get_velocity(), 100);
+            assert!((event.get_velocity() as i16 - 100).abs() <= 10);
+        } else {
+            assert_eq!(event.get_velocity(), 0);
+        }
+    }
+}
+
+#[test]
+fn test_track_diff() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::QUARTER);
+    assert!(track.diff(&track).is_empty());
+
+    let mut added = track.clone();
+    added.add_note(Note::from_str("G4").unwrap(), Beat::HALF);
+    assert_eq!(
+        track.diff(&added),
+        vec![TrackDiff::Added {
+            note: Note::from_str("G4").unwrap(),
+            start_ticks: 720,
+            duration_ticks: 720,
+        }]
+    );
+    assert_eq!(
+        added.diff(&track),
+        vec![TrackDiff::Removed {
+            note: Note::from_str("G4").unwrap(),
+            start_ticks: 720,
+            duration_ticks: 720,
+        }]
+    );
+
+    let mut moved = Track::new(120.0, Fraction::new(4, 4));
+    moved.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    moved.add_rest(Beat::QUARTER);
+    moved.add_note(Note::from_str("E4").unwrap(), Beat::QUARTER);
+    assert_eq!(
+        track.diff(&moved),
+        vec![TrackDiff::Moved {
+            note: Note::from_str("E4").unwrap(),
+            from_ticks: 360,
+            to_ticks: 720,
+            duration_ticks: 360,
+        }]
+    );
+
+    let mut changed = Track::new(120.0, Fraction::new(4, 4));
+    changed.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    changed.add_note(Note::from_str("F4").unwrap(), Beat::QUARTER);
+    assert_eq!(
+        track.diff(&changed),
+        vec![
+            TrackDiff::Removed {
+                note: Note::from_str("E4").unwrap(),
+                start_ticks: 360,
+                duration_ticks: 360,
+            },
+            TrackDiff::Added {
+                note: Note::from_str("F4").unwrap(),
+                start_ticks: 360,
+                duration_ticks: 360,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_track_pickup() {
+    let mut track = Track::new(120.0, Fraction::new(3, 4));
+    assert_eq!(track.get_pickup(), 0);
+    assert_eq!(track.get_measure_start(0), 0);
+    assert_eq!(track.get_measure_start(2), 2160);
+    track.set_pickup(Beat::QUARTER);
+    assert_eq!(track.get_pickup(), 360);
+    assert_eq!(track.get_measure_start(0), 360);
+    assert_eq!(track.get_measure_start(2), 2520);
+}
+
+#[test]
+fn test_tempo_map() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::WHOLE);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::WHOLE);
+    let mut midi = MIDI::new();
+    midi.add_track(track);
+    assert!(midi.get_tempo_map(120.0).is_empty());
+    midi.add_tempo_change(1440, 250000);
+    midi.add_tempo_change(0, 1000000);
+    midi.add_tempo_change(2160, 500000);
+    //Four quarter notes at 60 bpm followed by two quarter notes at 240 bpm
+    assert_eq!(
+        midi.get_tempo_map(120.0),
+        vec![
+            (Duration::ZERO, 1000000),
+            (Duration::from_secs(4), 250000),
+            (Duration::from_millis(4500), 500000),
+        ]
+    );
+    let path = std::env::temp_dir().join("music_tools_test_tempo_map.mid");
+    midi.export_to_file(path.to_str().unwrap()).unwrap();
+    let imported = MIDI::import_from_file(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(imported.get_tempo_map(120.0), midi.get_tempo_map(120.0));
+    assert_eq!(imported.get_tracks()[0].get_tempo(), 120.0);
+}
+
+#[test]
+fn test_consecutive_rests() {
+    let note = Note::from_str("C4").unwrap();
+    let mut eighth_rests = Track::new(120.0, Fraction::new(4, 4));
+    eighth_rests.add_note(note, Beat::QUARTER);
+    for _ in 0..3 {
+        eighth_rests.add_rest(Beat::EIGHTH);
+    }
+    eighth_rests.add_note(note, Beat::QUARTER);
+    let mut dotted_rest = Track::new(120.0, Fraction::new(4, 4));
+    dotted_rest.add_note(note, Beat::QUARTER);
+    dotted_rest.add_rest(Beat::QUARTER_DOTTED);
+    dotted_rest.add_note(note, Beat::QUARTER);
+    assert_eq!(eighth_rests.get_events(), dotted_rest.get_events());
+    assert_eq!(eighth_rests.get_duration(), dotted_rest.get_duration());
+    assert_eq!(eighth_rests.get_events().len(), 4);
+    assert_eq!(eighth_rests.get_events()[2].get_delta_ticks(), 540);
+    assert_eq!(eighth_rests.flatten(), dotted_rest.flatten());
+}
+
+#[test]
+fn test_merge_tied_notes() {
+    let note = Note::from_str("C4").unwrap();
+    let mut tied = Track::new(120.0, Fraction::new(4, 4));
+    tied.add_note(note, Beat::QUARTER);
+    tied.add_note(note, Beat::QUARTER);
+    tied.add_note(Note::from_str("D4").unwrap(), Beat::HALF);
+    tied.merge_tied_notes();
+    let mut expected = Track::new(120.0, Fraction::new(4, 4));
+    expected.add_note(note, Beat::HALF);
+    expected.add_note(Note::from_str("D4").unwrap(), Beat::HALF);
+    assert_eq!(tied.get_events(), expected.get_events());
+    assert_eq!(tied.get_duration(), expected.get_duration());
+    //Notes separated by a rest are not tied
+    let mut separated = Track::new(120.0, Fraction::new(4, 4));
+    separated.add_note(note, Beat::QUARTER);
+    separated.add_rest(Beat::QUARTER);
+    separated.add_note(note, Beat::QUARTER);
+    separated.merge_tied_notes();
+    assert_eq!(separated.get_events().len(), 4);
+    //Every note of repeated chords is tied
+    let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let mut chords = Track::new(120.0, Fraction::new(4, 4));
+    chords.add_chord(chord.clone(), Beat::QUARTER).unwrap();
+    chords.add_chord(chord.clone(), Beat::QUARTER).unwrap();
+    chords.merge_tied_notes();
+    let mut sustained = Track::new(120.0, Fraction::new(4, 4));
+    sustained.add_chord(chord, Beat::HALF).unwrap();
+    assert_eq!(chords.get_events(), sustained.get_events());
+}
+
+#[test]
+fn test_midi_writer() {
+    let mut melody = Track::new(120.0, Fraction::new(3, 4));
+    melody.add_note(Note::from_str("E4").unwrap(), Beat::QUARTER);
+    melody.add_rest(Beat::WHOLE);
+    melody.add_note(Note::from_str("G4").unwrap(), Beat::HALF);
+    let mut chords = Track::new(120.0, Fraction::new(3, 4));
+    let chord = Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), Some(3));
+    chords.add_chord(chord, Beat::HALF_DOTTED).unwrap();
+    let mut midi = MIDI::new();
+    midi.add_track(melody.clone());
+    midi.add_track(chords.clone());
+    let exported_path = std::env::temp_dir().join("music_tools_test_writer_exported.mid");
+    let written_path = std::env::temp_dir().join("music_tools_test_writer_written.mid");
+    midi.export_to_file(exported_path.to_str().unwrap())
+        .unwrap();
+    let mut writer = MidiWriter::try_new(
+        written_path.to_str().unwrap(),
+        120.0,
+        Fraction::new(3, 4),
+        360,
+    )
+    .unwrap();
+    for (index, track) in [melody, chords].iter().enumerate() {
+        for event in track.get_events() {
+            writer
+                .write_event(index, event.get_delta_ticks(), &event)
+                .unwrap();
+        }
+    }
+    let note = Note::from_str("C4").unwrap();
+    assert!(writer
+        .write_event(0, 0, &Event::new(note, true, 64))
+        .is_err());
+    writer.finish().unwrap();
+    let exported = MIDI::import_from_file(exported_path.to_str().unwrap()).unwrap();
+    let written = MIDI::import_from_file(written_path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&exported_path).unwrap();
+    std::fs::remove_file(&written_path).unwrap();
+    assert_eq!(written.get_num_tracks(), 2);
+    assert_eq!(written, exported);
+}
+
+#[test]
+fn test_track_to_rhythm() {
+    let mut track = Track::new(120.0, Fraction::new(4, 4));
+    track.add_note(Note::from_str("C4").unwrap(), Beat::QUARTER);
+    track.add_note(Note::from_str("D4").unwrap(), Beat::EIGHTH);
+    track.add_note(Note::from_str("E4").unwrap(), Beat::EIGHTH);
+    track.add_rest(Beat::QUARTER);
+    let chord = Chord::from_triad(TriadQuality::Major, Some(PitchClass::F), Some(4));
+    track.add_chord(chord, Beat::HALF_DOTTED).unwrap();
+    let rhythm = track.to_rhythm(90.0, Fraction::new(3, 4));
+    assert_eq!(
+        rhythm.get_beats(),
+        vec![Beat::QUARTER, Beat::EIGHTH, Beat::EIGHTH, Beat::HALF_DOTTED]
+    );
+    assert_eq!(rhythm.get_tempo(), 90.0);
+    assert_eq!(rhythm.get_time_signature(), Fraction::new(3, 4));
+    let mut triplets = Track::new(120.0, Fraction::new(4, 4));
+    triplets.add_note(Note::from_str("C4").unwrap(), Beat::new(1, 12));
+    assert_eq!(
+        triplets.to_rhythm(120.0, Fraction::new(4, 4)).get_beats(),
+        vec![Beat::new(1, 12)]
+    );
+}
@@ -0,0 +1,147 @@
+use music_tools::note::{
+    approx_equal_cents, cents_between, chromatic_run, midi_note_to_frequency, overtone_series, Note,
+};
+use music_tools::pitchclass::PitchClass;
+use std::str::FromStr;
+
+#[test]
+fn test_compact_notes() {
+    for name in ["C-1", "C#0", "A4", "Bb5", "G9", "B14"] {
+        let note = Note::from_str(name).unwrap();
+        let packed = note.to_compact_u8().unwrap();
+        assert_eq!(Note::from_compact_u8(packed), Some(note));
+    }
+    assert_eq!(Note::from_str("C-2").unwrap().to_compact_u8(), None);
+    assert_eq!(Note::from_str("C15").unwrap().to_compact_u8(), None);
+    assert_eq!(Note::from_compact_u8(0x0C), None);
+    for index in 0..12 {
+        let pitch_class = PitchClass::from_chromatic_index(index).unwrap();
+        assert_eq!(pitch_class.chromatic_index(), index);
+    }
+    assert_eq!(PitchClass::from_chromatic_index(12), None);
+}
+
+#[test]
+fn test_note_from_frequency() {
+    let a4 = Note::from_str("A4").unwrap();
+    let (note, cents) = Note::from_frequency(440.0, 440.0).unwrap();
+    assert_eq!(note, a4);
+    assert!(cents.abs() < 0.01);
+    let (note, cents) = Note::from_frequency(445.0, 440.0).unwrap();
+    assert_eq!(note, a4);
+    assert!(cents > 0.0);
+    let (note, cents) = Note::from_frequency(255.0, 440.0).unwrap();
+    assert_eq!(note, Note::from_str("C4").unwrap());
+    assert!(cents < 0.0);
+    let (note, cents) = Note::from_frequency(432.0, 432.0).unwrap();
+    assert_eq!(note.get_base_frequency(), 432.0);
+    assert_eq!(note.get_frequency(), 432.0);
+    assert!(cents.abs() < 0.01);
+    assert!(Note::from_frequency(0.0, 440.0).is_err());
+    assert!(Note::from_frequency(-440.0, 440.0).is_err());
+}
+
+#[test]
+fn test_cents_between() {
+    assert!((cents_between(440.0, 880.0) - 1200.0).abs() < 0.001);
+    assert_eq!(cents_between(440.0, 440.0), 0.0);
+    assert!((cents_between(880.0, 440.0) + 1200.0).abs() < 0.001);
+    let c4 = Note::from_str("C4").unwrap();
+    let c_sharp4 = Note::from_str("C#4").unwrap();
+    assert!((cents_between(c4.get_frequency(), c_sharp4.get_frequency()) - 100.0).abs() < 0.01);
+    assert!(approx_equal_cents(440.0, 440.5, 2.0));
+    assert!(approx_equal_cents(c4.get_frequency(), 261.63, 0.1));
+    assert!(!approx_equal_cents(440.0, 445.0, 10.0));
+}
+
+#[test]
+fn test_overtone_series() {
+    let harmonics = overtone_series(110.0, 8).unwrap();
+    assert_eq!(harmonics.len(), 8);
+    let names = ["A2", "A3", "E4", "A4", "C#5", "E5", "G5", "A5"];
+    for (index, (frequency, note, _)) in harmonics.iter().enumerate() {
+        assert_eq!(*frequency, 110.0 * (index + 1) as f32);
+        assert_eq!(*note, Note::from_str(names[index]).unwrap());
+    }
+    assert_eq!(harmonics[1].0, 220.0);
+    assert_eq!(harmonics[1].1, Note::from_str("A3").unwrap());
+    assert!(harmonics[1].2.abs() < 0.01);
+    assert!((harmonics[2].2 - 1.955).abs() < 0.01);
+    assert!((harmonics[4].2 + 13.686).abs() < 0.01);
+    assert!((harmonics[6].2 + 31.174).abs() < 0.01);
+    assert!(overtone_series(110.0, 0).unwrap().is_empty());
+    assert!(overtone_series(0.0, 4).is_err());
+}
+
+#[test]
+fn test_midi_note_to_frequency() {
+    assert_eq!(midi_note_to_frequency(69, 440.0), 440.0);
+    assert_eq!(midi_note_to_frequency(69, 432.0), 432.0);
+    assert!((midi_note_to_frequency(60, 440.0) - 261.63).abs() < 0.01);
+    assert!((midi_note_to_frequency(57, 440.0) - 220.0).abs() < 0.001);
+    for index in [0, 21, 60, 108, 127] {
+        let note = Note::from_midi_index(index).unwrap();
+        assert!(approx_equal_cents(
+            midi_note_to_frequency(index, 440.0),
+            note.get_frequency(),
+            0.01
+        ));
+    }
+}
+
+#[test]
+fn test_note_from_parts() {
+    assert_eq!(
+        Note::from_parts('F', 1, 4).unwrap(),
+        Note::from_str("F#4").unwrap()
+    );
+    assert_eq!(
+        Note::from_parts('B', -1, 3).unwrap(),
+        Note::from_str("Bb3").unwrap()
+    );
+    assert_eq!(
+        Note::from_parts('E', -2, 5).unwrap(),
+        Note::from_str("Ebb5").unwrap()
+    );
+    assert_eq!(
+        Note::from_parts('B', 1, 4).unwrap(),
+        Note::from_str("B#4").unwrap()
+    );
+    assert_eq!(Note::from_parts('A', 0, 4).unwrap().get_frequency(), 440.0);
+    assert!(Note::from_parts('H', 0, 4).is_err());
+    assert!(Note::from_parts('f', 0, 4).is_err());
+    assert!(Note::from_parts('C', 3, 4).is_err());
+}
+
+#[test]
+fn test_chromatic_run() {
+    let c4 = Note::from_str("C4").unwrap();
+    let e4 = Note::from_str("E4").unwrap();
+    assert_eq!(
+        chromatic_run(c4, e4, false),
+        vec!["C4", "C♯4", "D4", "D♯4", "E4"]
+    );
+    assert_eq!(
+        chromatic_run(e4, c4, true),
+        vec!["E4", "E♭4", "D4", "D♭4", "C4"]
+    );
+    let a3 = Note::from_str("A3").unwrap();
+    assert_eq!(
+        chromatic_run(a3, Note::from_str("C#4").unwrap(), true),
+        vec!["A3", "B♭3", "B3", "C4", "D♭4"]
+    );
+    assert_eq!(chromatic_run(c4, c4, false), vec!["C4"]);
+}
+
+#[test]
+fn test_frequency_at_reference() {
+    let ratio = 432.0 / 440.0;
+    for name in ["C0", "A4", "F#5", "Bb7"] {
+        let note = Note::from_str(name).unwrap();
+        let shifted = note.get_frequency_at_reference(432.0);
+        assert!((shifted / note.get_frequency() - ratio).abs() < 0.0001);
+        assert_eq!(note.get_base_frequency(), 440.0);
+    }
+    let a4 = Note::from_str("A4").unwrap();
+    assert_eq!(a4.get_frequency_at_reference(415.0), 415.0);
+}
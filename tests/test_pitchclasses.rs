@@ -0,0 +1,33 @@
+use music_tools::note::Note;
+use music_tools::pitchclass::PitchClass;
+
+#[test]
+fn test_pitch_class_from_value() {
+    assert_eq!(PitchClass::from_value(0), Some(PitchClass::C));
+    assert_eq!(PitchClass::from_value(11), Some(PitchClass::B));
+    assert_eq!(PitchClass::from_value(12), None);
+    for value in 0..12 {
+        assert_eq!(
+            PitchClass::from_value(value),
+            PitchClass::try_from(value).ok()
+        );
+    }
+    assert_eq!(
+        Note::from_midi_index(61).unwrap(),
+        Note::new(PitchClass::C_SHARP, 4)
+    );
+    assert!(Note::from_midi_index(128).is_err());
+}
+
+#[test]
+fn test_spelled_names() {
+    let a_sharp = PitchClass::from_value(10).unwrap();
+    assert_eq!(a_sharp.get_spelled_name(false), "A♯");
+    assert_eq!(a_sharp.get_spelled_name(true), "B♭");
+    assert_eq!(PitchClass::F_SHARP.get_spelled_name(true), "G♭");
+    assert_eq!(PitchClass::E.get_spelled_name(true), "E");
+    assert_eq!(PitchClass::E.get_spelled_name(false), "E");
+    let note = Note::from_midi_index(61).unwrap();
+    assert_eq!(note.get_spelled_name(false), "C♯4");
+    assert_eq!(note.get_spelled_name(true), "D♭4");
+}
@@ -0,0 +1,134 @@
+use music_tools::common::{Beat, Fraction};
+use music_tools::rhythm::Rhythm;
+
+#[test]
+fn test_rhythm_to_ascii() {
+    let common_time = Fraction::new(4, 4);
+    let rhythm = Rhythm::new(
+        120.0,
+        common_time,
+        &[Beat::QUARTER, Beat::EIGHTH, Beat::EIGHTH],
+    );
+    assert_eq!(rhythm.to_ascii(), "x-xx");
+    let sixteenths = Rhythm::new(
+        120.0,
+        common_time,
+        &[Beat::QUARTER, Beat::SIXTEENTH, Beat::EIGHTH_DOTTED],
+    );
+    assert_eq!(sixteenths.to_ascii(), "x---xx--");
+    let whole = Rhythm::new(120.0, common_time, &[Beat::WHOLE]);
+    assert_eq!(whole.to_ascii(), "x");
+    assert_eq!(Rhythm::default().to_ascii(), "");
+}
+
+#[test]
+fn test_rhythm_from_pattern() {
+    let quintuple_time = Fraction::new(5, 4);
+    let rhythm = Rhythm::from_pattern(120.0, quintuple_time, "q. q. q q").unwrap();
+    assert_eq!(
+        rhythm,
+        Rhythm::new(
+            120.0,
+            quintuple_time,
+            &[
+                Beat::QUARTER_DOTTED,
+                Beat::QUARTER_DOTTED,
+                Beat::QUARTER,
+                Beat::QUARTER
+            ],
+        )
+    );
+    let mixed = Rhythm::from_pattern(90.0, Fraction::new(4, 4), " w  h. e\ts t. ").unwrap();
+    assert_eq!(
+        mixed.get_beats(),
+        vec![
+            Beat::WHOLE,
+            Beat::HALF_DOTTED,
+            Beat::EIGHTH,
+            Beat::SIXTEENTH,
+            Beat::THIRTYSECOND_DOTTED
+        ]
+    );
+    assert!(Rhythm::from_pattern(120.0, quintuple_time, "")
+        .unwrap()
+        .get_beats()
+        .is_empty());
+    assert!(Rhythm::from_pattern(120.0, quintuple_time, "q x").is_err());
+    assert!(Rhythm::from_pattern(120.0, quintuple_time, "q..").is_err());
+}
+
+#[test]
+fn test_rhythm_append_and_repeat() {
+    let mut rhythm = Rhythm::new(
+        120.0,
+        Fraction::new(3, 4),
+        &[Beat::QUARTER, Beat::EIGHTH, Beat::EIGHTH],
+    );
+    let other = Rhythm::new(90.0, Fraction::new(4, 4), &[Beat::HALF, Beat::HALF]);
+    rhythm.append(&other);
+    assert_eq!(
+        rhythm.get_beats(),
+        vec![
+            Beat::QUARTER,
+            Beat::EIGHTH,
+            Beat::EIGHTH,
+            Beat::HALF,
+            Beat::HALF
+        ]
+    );
+    assert_eq!(rhythm.get_tempo(), 120.0);
+    assert_eq!(rhythm.get_time_signature(), Fraction::new(3, 4));
+    let repeated = other.repeat(3);
+    assert_eq!(repeated.get_beats(), vec![Beat::HALF; 6]);
+    assert_eq!(repeated.get_tempo(), 90.0);
+    assert!(other.repeat(0).get_beats().is_empty());
+}
+
+#[test]
+fn test_rhythm_pickup() {
+    let mut rhythm = Rhythm::from_pattern(120.0, Fraction::new(4, 4), "q q q q q q q q").unwrap();
+    assert_eq!(rhythm.get_pickup(), Beat::new(0, 1));
+    assert_eq!(rhythm.get_measure_starts(), vec![0, 4]);
+    rhythm.set_pickup(Beat::QUARTER);
+    assert_eq!(rhythm.get_measure_starts(), vec![1, 5]);
+    assert_eq!(rhythm.repeat(2).get_pickup(), Beat::QUARTER);
+    let mut eighths = Rhythm::from_pattern(120.0, Fraction::new(2, 4), "e e e q q e").unwrap();
+    eighths.set_pickup(Beat::EIGHTH);
+    assert_eq!(eighths.get_measure_starts(), vec![1, 4]);
+}
+
+#[test]
+fn test_rhythm_onsets() {
+    let rhythm = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::QUARTER; 4]);
+    assert_eq!(
+        rhythm.onsets().unwrap(),
+        vec![
+            Fraction::new(0, 1),
+            Fraction::new(1, 4),
+            Fraction::new(2, 4),
+            Fraction::new(3, 4)
+        ]
+    );
+    assert_eq!(rhythm.onset_seconds(), vec![0.0, 0.5, 1.0, 1.5]);
+    let dotted = Rhythm::from_pattern(60.0, Fraction::new(4, 4), "q. e s s").unwrap();
+    assert_eq!(
+        dotted.onsets().unwrap(),
+        vec![
+            Fraction::new(0, 1),
+            Fraction::new(3, 8),
+            Fraction::new(1, 2),
+            Fraction::new(9, 16)
+        ]
+    );
+    assert_eq!(dotted.onset_seconds(), vec![0.0, 1.5, 2.0, 2.25]);
+    assert!(Rhythm::new(120.0, Fraction::new(4, 4), &[])
+        .onsets()
+        .unwrap()
+        .is_empty());
+    //Forty measures of eighth notes have onsets that do not fit in a Fraction
+    let long = Rhythm::new(120.0, Fraction::new(4, 4), &[Beat::EIGHTH; 320]);
+    assert!(long.onsets().is_err());
+    let seconds = long.onset_seconds();
+    assert_eq!(seconds.len(), 320);
+    assert_eq!(seconds[319], 79.75);
+}
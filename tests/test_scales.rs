@@ -1,6 +1,9 @@
-use music_tools::common::{PentatonicType, ScaleType};
+use music_tools::chord::Chord;
+use music_tools::common::{PentatonicType, ScaleType, TriadQuality};
+use music_tools::interval::Interval;
+use music_tools::note::Note;
 use music_tools::pitchclass::PitchClass;
-use music_tools::scale::Scale;
+use music_tools::scale::{best_scale_for_progression, scales_containing, Scale};
 use std::cmp;
 use std::str::FromStr;
 
@@ -39,3 +42,405 @@ fn test_scale_notes() {
         }
     }
 }
+
+#[test]
+fn test_scale_note_iter() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let notes: Vec<Note> = major
+        .note_iter(Note::from_str("C4").unwrap())
+        .take(15)
+        .collect();
+    let expected: Vec<Note> = [
+        "C4", "D4", "E4", "F4", "G4", "A4", "B4", "C5", "D5", "E5", "F5", "G5", "A5", "B5", "C6",
+    ]
+    .into_iter()
+    .map(|x| Note::from_str(x).unwrap())
+    .collect();
+    assert_eq!(notes, expected);
+    let highest: Vec<Note> = major.note_iter(Note::new(PitchClass::G, 126)).collect();
+    assert_eq!(highest.len(), 10);
+    assert_eq!(highest.last(), Some(&Note::new(PitchClass::B, 127)));
+}
+
+#[test]
+fn test_additional_scales() {
+    let semitones = |scale_type: ScaleType| -> Vec<u64> {
+        Scale::try_new(scale_type, PentatonicType::None)
+            .unwrap()
+            .get_intervals()
+            .iter()
+            .map(|interval| interval.get_value())
+            .collect()
+    };
+    let bebop_dominant = semitones(ScaleType::BebopDominant);
+    assert_eq!(bebop_dominant.len(), 9);
+    assert_eq!(bebop_dominant, vec![0, 2, 4, 5, 7, 9, 10, 11, 12]);
+    assert_eq!(
+        semitones(ScaleType::BebopMajor),
+        vec![0, 2, 4, 5, 7, 8, 9, 11, 12]
+    );
+    assert_eq!(semitones(ScaleType::Augmented), vec![0, 3, 4, 7, 8, 11, 12]);
+    let hungarian_minor = semitones(ScaleType::HungarianMinor);
+    assert_eq!(hungarian_minor.len(), 8);
+    //The Hungarian minor scale contains augmented seconds between the third and fourth degrees and
+    //between the sixth and seventh degrees
+    assert_eq!(hungarian_minor[3] - hungarian_minor[2], 3);
+    assert_eq!(hungarian_minor[6] - hungarian_minor[5], 3);
+    assert_ne!(ScaleType::BebopDominant, ScaleType::BebopMajor);
+}
+
+#[test]
+fn test_scale_tetrachords() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    assert_eq!(
+        major.tetrachords().unwrap(),
+        (vec![0, 2, 4, 5], vec![7, 9, 11, 12])
+    );
+    let phrygian = Scale::try_new(ScaleType::Phrygian, PentatonicType::None).unwrap();
+    assert_eq!(
+        phrygian.tetrachords().unwrap(),
+        (vec![0, 1, 3, 5], vec![7, 8, 10, 12])
+    );
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert!(pentatonic.tetrachords().is_err());
+}
+
+#[test]
+fn test_scale_from_steps() {
+    let major = Scale::from_steps(&[2, 2, 1, 2, 2, 2, 1], "Ionian").unwrap();
+    assert_eq!(
+        major,
+        Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap()
+    );
+    assert_eq!(major.get_name(), "Ionian");
+    let harmonic_minor = Scale::from_steps(&[2, 1, 2, 2, 1, 3, 1], "Harmonic minor").unwrap();
+    assert_eq!(harmonic_minor.get_scale_type(), ScaleType::HarmonicMinor);
+    let pentatonic = Scale::from_steps(&[2, 2, 3, 2, 3], "Gong").unwrap();
+    assert_eq!(
+        pentatonic.get_intervals(),
+        Scale::try_new(ScaleType::Major, PentatonicType::Major)
+            .unwrap()
+            .get_intervals()
+    );
+    let hirajoshi = Scale::from_steps(&[2, 1, 4, 1, 4], "Hirajoshi").unwrap();
+    assert_eq!(hirajoshi.get_scale_type(), ScaleType::Custom);
+    assert_eq!(hirajoshi.get_name(), "Hirajoshi");
+    assert_eq!(
+        hirajoshi.to_pitch_classes(PitchClass::A),
+        vec![
+            PitchClass::A,
+            PitchClass::B,
+            PitchClass::C,
+            PitchClass::E,
+            PitchClass::F,
+            PitchClass::A
+        ]
+    );
+    let in_scale = Scale::from_steps(&[1, 4, 2, 1, 4], "In").unwrap();
+    assert_ne!(hirajoshi, in_scale);
+    assert_eq!(
+        hirajoshi,
+        Scale::from_steps(&[2, 1, 4, 1, 4], "Other").unwrap()
+    );
+    assert!(Scale::try_new(ScaleType::Custom, PentatonicType::None).is_err());
+    assert!(Scale::from_steps(&[2, 2, 1, 2, 2, 2], "Short").is_err());
+    assert!(Scale::from_steps(&[2, 2, 1, 2, 2, 2, 0, 1], "Zero").is_err());
+}
+
+#[test]
+fn test_scale_snap() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let snap = |scale: &Scale, tonic: PitchClass, name: &str| {
+        scale.snap(&tonic, &Note::from_str(name).unwrap())
+    };
+    assert_eq!(
+        snap(&major, PitchClass::C, "C#4"),
+        Note::from_str("C4").unwrap()
+    );
+    assert_eq!(
+        snap(&major, PitchClass::C, "E4"),
+        Note::from_str("E4").unwrap()
+    );
+    assert_eq!(
+        snap(&major, PitchClass::C, "A#3"),
+        Note::from_str("A3").unwrap()
+    );
+    assert_eq!(
+        snap(&major, PitchClass::D, "C4"),
+        Note::from_str("B3").unwrap()
+    );
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert_eq!(
+        snap(&pentatonic, PitchClass::C, "B3"),
+        Note::from_str("C4").unwrap()
+    );
+    assert_eq!(
+        snap(&pentatonic, PitchClass::C, "F4"),
+        Note::from_str("E4").unwrap()
+    );
+}
+
+#[test]
+fn test_primary_chords() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    assert_eq!(
+        major.tonic_chord(PitchClass::C, Some(4)).unwrap(),
+        Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4))
+    );
+    assert_eq!(
+        major.subdominant_chord(PitchClass::C, Some(4)).unwrap(),
+        Chord::from_triad(TriadQuality::Major, Some(PitchClass::F), Some(4))
+    );
+    assert_eq!(
+        major.dominant_chord(PitchClass::C, Some(4)).unwrap(),
+        Chord::from_triad(TriadQuality::Major, Some(PitchClass::G), Some(4))
+    );
+    let minor = Scale::try_new(ScaleType::Minor, PentatonicType::None).unwrap();
+    assert_eq!(
+        minor.tonic_chord(PitchClass::A, None).unwrap(),
+        Chord::from_triad(TriadQuality::Minor, Some(PitchClass::A), None)
+    );
+    assert_eq!(
+        minor.subdominant_chord(PitchClass::A, None).unwrap(),
+        Chord::from_triad(TriadQuality::Minor, Some(PitchClass::D), None)
+    );
+    let whole = Scale::try_new(ScaleType::Whole, PentatonicType::None).unwrap();
+    assert!(whole.tonic_chord(PitchClass::C, Some(4)).is_err());
+    assert!(whole.dominant_chord(PitchClass::C, Some(4)).is_err());
+}
+
+#[test]
+fn test_scale_names() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    assert_eq!(major.get_name(), "Major");
+    let harmonic_minor = Scale::try_new(ScaleType::HarmonicMinor, PentatonicType::None).unwrap();
+    assert_eq!(harmonic_minor.get_name(), "HarmonicMinor");
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert_eq!(pentatonic.get_name(), "Major (Major Pentatonic)");
+    let renamed = major.clone().with_name("Ionian");
+    assert_eq!(renamed.get_name(), "Ionian");
+    assert_eq!(renamed.get_intervals(), major.get_intervals());
+    assert_eq!(renamed, major);
+}
+
+#[test]
+fn test_scale_symmetry() {
+    let symmetry = |scale_type: ScaleType| {
+        Scale::try_new(scale_type, PentatonicType::None)
+            .unwrap()
+            .is_symmetric()
+    };
+    assert_eq!(symmetry(ScaleType::Whole), Some(2));
+    assert_eq!(symmetry(ScaleType::Diminished), Some(3));
+    assert_eq!(symmetry(ScaleType::DominantDiminished), Some(3));
+    assert_eq!(symmetry(ScaleType::Augmented), Some(4));
+    assert_eq!(symmetry(ScaleType::Chromatic), Some(1));
+    assert_eq!(symmetry(ScaleType::Major), None);
+    assert_eq!(symmetry(ScaleType::HarmonicMinor), None);
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert_eq!(pentatonic.is_symmetric(), None);
+}
+
+#[test]
+fn test_diatonic_chords_with_seventh() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let triads = major
+        .get_diatonic_chords(PitchClass::C, Some(4), false)
+        .unwrap();
+    let sevenths = major
+        .get_diatonic_chords(PitchClass::C, Some(4), true)
+        .unwrap();
+    assert_eq!(triads.len(), 7);
+    assert_eq!(sevenths.len(), 7);
+    let qualities = [
+        TriadQuality::Major,
+        TriadQuality::Minor,
+        TriadQuality::Minor,
+        TriadQuality::Major,
+        TriadQuality::Major,
+        TriadQuality::Minor,
+        TriadQuality::Diminished,
+    ];
+    let sevenths_intervals = [
+        Interval::MAJOR_SEVENTH,
+        Interval::MINOR_SEVENTH,
+        Interval::MINOR_SEVENTH,
+        Interval::MAJOR_SEVENTH,
+        Interval::MINOR_SEVENTH,
+        Interval::MINOR_SEVENTH,
+        Interval::MINOR_SEVENTH,
+    ];
+    for (index, (quality, seventh_interval)) in qualities.iter().zip(sevenths_intervals).enumerate()
+    {
+        let triad = Chord::from_triad(
+            *quality,
+            triads[index].get_tonic(),
+            triads[index].get_octave(),
+        );
+        assert_eq!(triads[index], triad);
+        assert_eq!(triads[index].get_intervals().len(), 3);
+        let mut seventh = triad.clone();
+        seventh.add_interval(seventh_interval);
+        assert_eq!(sevenths[index], seventh);
+        assert_eq!(sevenths[index].get_intervals().len(), 4);
+    }
+}
+
+#[test]
+fn test_scales_containing() {
+    let notes = ["C4", "D4", "E4", "G4", "A4"].map(|name| Note::from_str(name).unwrap());
+    let major_pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    let minor_pentatonic = Scale::try_new(ScaleType::Minor, PentatonicType::Minor).unwrap();
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let whole = Scale::try_new(ScaleType::Whole, PentatonicType::None).unwrap();
+    let candidates = [
+        major_pentatonic.clone(),
+        minor_pentatonic.clone(),
+        major.clone(),
+        whole,
+    ];
+    let matches = scales_containing(&notes, &candidates);
+    assert_eq!(
+        matches,
+        vec![
+            (major_pentatonic, PitchClass::C),
+            (minor_pentatonic, PitchClass::A),
+            (major.clone(), PitchClass::C),
+            (major.clone(), PitchClass::F),
+            (major, PitchClass::G),
+        ]
+    );
+    assert!(scales_containing(&notes, &[]).is_empty());
+    assert_eq!(scales_containing(&[], &candidates).len(), 48);
+}
+
+#[test]
+fn test_scale_spelled_names() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    assert_eq!(
+        major.get_spelled_names("D").unwrap(),
+        vec!["D", "E", "F♯", "G", "A", "B", "C♯"]
+    );
+    assert_eq!(
+        major.get_spelled_names("C").unwrap(),
+        vec!["C", "D", "E", "F", "G", "A", "B"]
+    );
+    assert_eq!(
+        major.get_spelled_names("Gb").unwrap(),
+        vec!["G♭", "A♭", "B♭", "C♭", "D♭", "E♭", "F"]
+    );
+    let harmonic_minor = Scale::try_new(ScaleType::HarmonicMinor, PentatonicType::None).unwrap();
+    assert_eq!(
+        harmonic_minor.get_spelled_names("C").unwrap(),
+        vec!["C", "D", "E♭", "F", "G", "A♭", "B"]
+    );
+    let d_major = major.to_pitch_classes(PitchClass::D);
+    assert_eq!(d_major[2], PitchClass::F_SHARP);
+    assert_eq!(d_major[6], PitchClass::C_SHARP);
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert!(pentatonic.get_spelled_names("C").is_err());
+    assert!(major.get_spelled_names("H").is_err());
+}
+
+#[test]
+fn test_scale_degree_numerals() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    assert_eq!(
+        major.degree_numerals().unwrap(),
+        vec!["I", "ii", "iii", "IV", "V", "vi", "vii°"]
+    );
+    let harmonic_minor = Scale::try_new(ScaleType::HarmonicMinor, PentatonicType::None).unwrap();
+    assert_eq!(
+        harmonic_minor.degree_numerals().unwrap(),
+        vec!["i", "ii°", "bIII+", "iv", "V", "bVI", "vii°"]
+    );
+    for scale_type in [
+        ScaleType::Major,
+        ScaleType::Dorian,
+        ScaleType::Phrygian,
+        ScaleType::Lydian,
+        ScaleType::Mixolydian,
+        ScaleType::Locrian,
+    ] {
+        let scale = Scale::try_new(scale_type, PentatonicType::None).unwrap();
+        let chords: Vec<Chord> = scale
+            .degree_numerals()
+            .unwrap()
+            .iter()
+            .map(|numeral| Chord::from_numeral(numeral, PitchClass::D, Some(3)).unwrap())
+            .collect();
+        assert_eq!(
+            chords,
+            scale
+                .get_diatonic_chords(PitchClass::D, Some(3), false)
+                .unwrap()
+        );
+    }
+    let pentatonic = Scale::try_new(ScaleType::Major, PentatonicType::Major).unwrap();
+    assert!(pentatonic.degree_numerals().is_err());
+}
+
+#[test]
+fn test_contains_chord() {
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let c_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::C), Some(4));
+    let d_major = Chord::from_triad(TriadQuality::Major, Some(PitchClass::D), Some(4));
+    assert!(major.contains_chord(&PitchClass::C, &c_major));
+    assert!(!major.contains_chord(&PitchClass::C, &d_major));
+    assert!(major.contains_chord(&PitchClass::D, &d_major));
+    let mut g_dominant = Chord::from_triad(TriadQuality::Major, Some(PitchClass::G), None);
+    g_dominant.add_interval(Interval::MINOR_SEVENTH);
+    assert!(major.contains_chord(&PitchClass::C, &g_dominant));
+    let minor = Scale::try_new(ScaleType::Minor, PentatonicType::None).unwrap();
+    assert!(!minor.contains_chord(&PitchClass::C, &g_dominant));
+    let no_tonic = Chord::from_triad(TriadQuality::Major, None, None);
+    assert!(!major.contains_chord(&PitchClass::C, &no_tonic));
+}
+
+#[test]
+fn test_best_scale_for_progression() {
+    let chords = ["I", "IV", "V"]
+        .map(|numeral| Chord::from_numeral(numeral, PitchClass::C, Some(4)).unwrap());
+    let major = Scale::try_new(ScaleType::Major, PentatonicType::None).unwrap();
+    let minor = Scale::try_new(ScaleType::Minor, PentatonicType::None).unwrap();
+    let harmonic_minor = Scale::try_new(ScaleType::HarmonicMinor, PentatonicType::None).unwrap();
+    assert_eq!(
+        best_scale_for_progression(&chords, &[minor.clone(), major.clone()]),
+        Some((minor.clone(), PitchClass::A))
+    );
+    assert_eq!(
+        best_scale_for_progression(&chords, &[major.clone(), minor.clone()]),
+        Some((major.clone(), PitchClass::C))
+    );
+    let minor_chords =
+        ["i", "iv", "V"].map(|numeral| Chord::from_numeral(numeral, PitchClass::A, None).unwrap());
+    assert_eq!(
+        best_scale_for_progression(&minor_chords, &[major, minor, harmonic_minor.clone()]),
+        Some((harmonic_minor.clone(), PitchClass::A))
+    );
+    assert_eq!(best_scale_for_progression(&chords, &[]), None);
+}
+
+#[test]
+fn test_brightness() {
+    let brightness = |scale_type: ScaleType| {
+        Scale::try_new(scale_type, PentatonicType::None)
+            .unwrap()
+            .brightness()
+    };
+    let modes = [
+        ScaleType::Lydian,
+        ScaleType::Major,
+        ScaleType::Mixolydian,
+        ScaleType::Dorian,
+        ScaleType::Minor,
+        ScaleType::Phrygian,
+        ScaleType::Locrian,
+    ];
+    for (expected, mode) in (-5..=1).rev().zip(modes) {
+        assert_eq!(brightness(mode), expected);
+    }
+    assert!(brightness(ScaleType::Lydian) > brightness(ScaleType::Major));
+    assert!(brightness(ScaleType::Major) > brightness(ScaleType::Locrian));
+    assert_eq!(brightness(ScaleType::HarmonicMinor), -2);
+}